@@ -0,0 +1,297 @@
+use std::fs; // OS crate
+use std::collections::{BTreeMap, HashSet}; // Custom fields and duplicate checks
+use std::path::{Path, PathBuf}; // File path crate
+use chrono::NaiveDate; // Dates given per row
+
+use crate::{NewProject, ProjectType};
+use crate::podcast::{self, PodcastInfo};
+use crate::school::{Course, SchoolInfo};
+
+// One project requested by a line of the batch text
+pub struct BatchRow {
+    pub line: usize, // Line number in the batch text (1 based, header included)
+    pub project: Result<NewProject, String>, // Project to create, or why the row is invalid
+}
+
+// What happened to one row after the batch ran
+pub struct RowResult {
+    pub line: usize, // Line number in the batch text
+    pub name: String, // Project name from the row
+    pub outcome: Result<PathBuf, String>, // Created path, or why it was not created
+    pub project_type: ProjectType, // Type from the row, picks the post-create hooks of a created project
+}
+
+/*
+Purpose: Reads batch text into projects. Text with a header containing "name" is read as CSV
+         (name, type, date, start/end/courses for School rows and any custom columns), otherwise every non-empty line is a project name.
+Args: text (String) - CSV or plain list
+      default_type (ProjectType) - type used when a row has no type
+      default_date (NaiveDate) - date used when a row has no date
+Return: One entry per project row
+*/
+pub fn parse(text: &str, default_type: ProjectType, default_date: NaiveDate) -> Vec<BatchRow> {
+    let first_line = text.lines().find(|l| !l.trim().is_empty()).unwrap_or("");
+    let is_csv = first_line.split(',').any(|h| h.trim().eq_ignore_ascii_case("name"));
+
+    if !is_csv {
+        return text
+            .lines()
+            .enumerate()
+            .filter(|(_, l)| !l.trim().is_empty())
+            .map(|(i, l)| {
+                let mut project = NewProject::new(l, default_type);
+                project.date = default_date;
+                if default_type == ProjectType::School {
+                    project.school = Some(SchoolInfo::new(None, None, Vec::new()));
+                }
+                BatchRow { line: i + 1, project: Ok(project) }
+            })
+            .collect();
+    }
+
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .flexible(true)
+        .from_reader(text.as_bytes());
+
+    let headers: Vec<String> = match reader.headers() {
+        Ok(h) => h.iter().map(|h| h.to_ascii_lowercase()).collect(),
+        Err(e) => return vec![BatchRow { line: 1, project: Err(format!("Bad header: {}", e)) }],
+    };
+
+    let mut rows = Vec::new();
+    for record in reader.records() {
+        let (line, project) = match record {
+            Ok(record) => {
+                let line = record.position().map(|p| p.line() as usize).unwrap_or(0);
+                (line, parse_record(&headers, &record, default_type, default_date))
+            }
+            Err(e) => (e.position().map(|p| p.line() as usize).unwrap_or(0), Err(e.to_string())),
+        };
+        rows.push(BatchRow { line, project });
+    }
+    rows
+}
+
+// Purpose: Turns one CSV record into a project, checking the type and date columns
+fn parse_record(headers: &[String], record: &csv::StringRecord, default_type: ProjectType, default_date: NaiveDate) -> Result<NewProject, String> {
+    let mut name = "";
+    let mut project_type = default_type;
+    let mut date = default_date;
+    let mut fields = BTreeMap::new();
+
+    for (header, value) in headers.iter().zip(record.iter()) {
+        match header.as_str() {
+            "name" => name = value,
+            "type" if !value.is_empty() => {
+                project_type = ProjectType::from_name(value).ok_or(format!("Unknown project type '{}'", value))?;
+            }
+            "date" if !value.is_empty() => {
+                date = NaiveDate::parse_from_str(value, "%Y-%m-%d").map_err(|_| format!("Bad date '{}' (use YYYY-MM-DD)", value))?;
+            }
+            "type" | "date" => {}
+            _ => {
+                if !value.is_empty() {
+                    fields.insert(header.clone(), value.to_string());
+                }
+            }
+        }
+    }
+
+    let mut project = NewProject::new(name, project_type);
+    project.date = date;
+    if project_type == ProjectType::Podcast {
+        // Episode numbers are handed out in run, once the base folder is known
+        let speakers = fields.remove("speakers").unwrap_or_default();
+        project.podcast = Some(PodcastInfo::new(0, podcast::parse_speakers(&speakers)));
+    }
+    if project_type == ProjectType::School {
+        // Semester dates and courses ("CS101 Intro, MATH201 Calculus") have their own columns
+        let mut semester_date = |column: &str| {
+            fields
+                .remove(column)
+                .map(|value| NaiveDate::parse_from_str(&value, "%Y-%m-%d").map_err(|_| format!("Bad {} date '{}' (use YYYY-MM-DD)", column, value)))
+                .transpose()
+        };
+        let start = semester_date("start")?;
+        let end = semester_date("end")?;
+        let courses = fields.remove("courses").unwrap_or_default().split([',', ';']).filter_map(Course::parse).collect();
+        project.school = Some(SchoolInfo::new(start, end, courses));
+    }
+    project.fields = fields;
+    Ok(project)
+}
+
+// Purpose: Finds problems that would stop a row from being created
+fn check_row(base_dir: &Path, project: &NewProject, seen: &mut HashSet<String>) -> Result<(), String> {
+    if project.name.is_empty() {
+        return Err("Name cannot be empty".to_string());
+    }
+    if project.project_type == ProjectType::None {
+        return Err("No project type selected".to_string());
+    }
+
+    let folder = project.folder_name();
+    if base_dir.join(&folder).exists() {
+        return Err(format!("Folder '{}' already exists", folder));
+    }
+    // Every podcast row gets its own episode number, so the same episode name twice is the duplicate
+    let key = match &project.podcast {
+        Some(_) => format!("EP_{}", project.name),
+        None => folder.clone(),
+    };
+    if !seen.insert(key.to_ascii_lowercase()) {
+        return Err(format!("Folder '{}' appears twice in the batch", folder));
+    }
+    Ok(())
+}
+
+/*
+Purpose: Creates every project in a batch through the normal project creation path
+Args: base_dir (Path) - base folder the projects are placed in
+      rows (BatchRow list) - rows from parse
+      all_or_nothing (bool) - if any row fails nothing is kept
+Return: Result for every row in order
+*/
+pub fn run(base_dir: &Path, rows: Vec<BatchRow>, all_or_nothing: bool) -> Vec<RowResult> {
+    let mut seen = HashSet::new();
+    let mut checked: Vec<(usize, String, Result<NewProject, String>)> = Vec::new();
+    let mut next_episode = podcast::next_episode(base_dir);
+
+    for row in rows {
+        let name = row.project.as_ref().map(|p| p.name.clone()).unwrap_or_default();
+        let project = row.project.and_then(|mut p| {
+            // Podcast rows get the next episode numbers in the order they are listed, a rejected row keeps its number free
+            let is_podcast = p.project_type == ProjectType::Podcast;
+            if is_podcast {
+                p.podcast.get_or_insert_with(|| PodcastInfo::new(0, Vec::new())).episode = next_episode;
+            }
+            check_row(base_dir, &p, &mut seen)?;
+            if is_podcast {
+                next_episode += 1;
+            }
+            Ok(p)
+        });
+        checked.push((row.line, name, project));
+    }
+
+    // Nothing is touched if a row is already known to be bad
+    if all_or_nothing && checked.iter().any(|(_, _, p)| p.is_err()) {
+        return checked
+            .into_iter()
+            .map(|(line, name, project)| RowResult {
+                line,
+                name,
+                project_type: project.as_ref().map(|p| p.project_type).unwrap_or_default(),
+                outcome: Err(project.err().unwrap_or_else(|| "Skipped, another row is invalid".to_string())),
+            })
+            .collect();
+    }
+
+    let mut results: Vec<RowResult> = checked
+        .into_iter()
+        .map(|(line, name, project)| {
+            let project_type = project.as_ref().map(|p| p.project_type).unwrap_or_default();
+            RowResult { line, name, outcome: project.and_then(|p| p.create(base_dir).map_err(|e| e.to_string())), project_type }
+        })
+        .collect();
+
+    // A failure while creating removes the projects this batch already made (a failed row removes its own folder)
+    if all_or_nothing && results.iter().any(|r| r.outcome.is_err()) {
+        for result in &mut results {
+            if let Ok(path) = &result.outcome {
+                let _ = fs::remove_dir_all(path);
+                result.outcome = Err("Rolled back, another row failed".to_string());
+            }
+        }
+    }
+
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Purpose: Gets the default date the tests parse with
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 1, 31).unwrap()
+    }
+
+    #[test]
+    fn reads_a_plain_list_of_names() {
+        let rows = parse("First video\n\n  Second video  \n", ProjectType::Youtube, today());
+
+        assert_eq!(rows.iter().map(|r| r.line).collect::<Vec<_>>(), vec![1, 3]);
+        let project = rows[1].project.as_ref().unwrap();
+        assert_eq!(project.name, "Second video");
+        assert_eq!(project.project_type, ProjectType::Youtube);
+        assert_eq!(project.date, today());
+    }
+
+    #[test]
+    fn reads_csv_columns_and_custom_fields() {
+        let text = "Name,Type,Date,Sponsor\nMy video,shorts,2026-03-01,Acme\nOther,,,\n";
+        let rows = parse(text, ProjectType::Youtube, today());
+
+        let first = rows[0].project.as_ref().unwrap();
+        assert_eq!(rows[0].line, 2);
+        assert_eq!(first.project_type, ProjectType::Shorts);
+        assert_eq!(first.date, NaiveDate::from_ymd_opt(2026, 3, 1).unwrap());
+        assert_eq!(first.fields.get("sponsor").map(|s| s.as_str()), Some("Acme"));
+
+        // Empty cells fall back to the defaults
+        let second = rows[1].project.as_ref().unwrap();
+        assert_eq!((second.project_type, second.date), (ProjectType::Youtube, today()));
+        assert!(second.fields.is_empty());
+    }
+
+    #[test]
+    fn keeps_commas_and_quotes_inside_quoted_fields() {
+        let text = "name,type\n\"Hello, \"\"world\"\"\",youtube\n";
+        let rows = parse(text, ProjectType::None, today());
+
+        assert_eq!(rows[0].project.as_ref().unwrap().name, "Hello, \"world\"");
+    }
+
+    #[test]
+    fn reports_bad_types_and_dates_per_row() {
+        let text = "name,type,date\nA,vlog,\nB,,31/01/2026\nC,school,\n";
+        let rows = parse(text, ProjectType::Youtube, today());
+
+        assert_eq!(rows[0].project.as_ref().err().map(|e| e.as_str()), Some("Unknown project type 'vlog'"));
+        assert!(rows[1].project.as_ref().is_err_and(|e| e.starts_with("Bad date '31/01/2026'")));
+        assert!(rows[2].project.is_ok());
+    }
+
+    #[test]
+    fn reads_school_semesters_and_courses() {
+        let text = "name,type,start,end,courses\nSpring,school,2026-01-12,2026-05-01,\"CS101 Intro; MATH201 Calculus\"\n";
+        let rows = parse(text, ProjectType::Youtube, today());
+
+        let project = rows[0].project.as_ref().unwrap();
+        let school = project.school.as_ref().unwrap();
+        assert_eq!(school.start, NaiveDate::from_ymd_opt(2026, 1, 12));
+        assert_eq!(school.courses.iter().map(|c| c.code.as_str()).collect::<Vec<_>>(), vec!["CS101", "MATH201"]);
+        assert!(project.fields.is_empty());
+    }
+
+    #[test]
+    fn numbers_only_the_podcast_episodes_that_are_created() {
+        let base = std::env::temp_dir().join(format!("y_template_batch_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&base);
+        fs::create_dir_all(&base).unwrap();
+
+        let rows = parse("name,type\nFirst,podcast\nfirst,podcast\nSecond,podcast\n", ProjectType::Youtube, today());
+        let results = run(&base, rows, false);
+        let created: Vec<String> = results
+            .iter()
+            .filter_map(|r| r.outcome.as_ref().ok())
+            .map(|path| path.file_name().unwrap().to_string_lossy().to_string())
+            .collect();
+        let _ = fs::remove_dir_all(&base);
+
+        assert!(results[1].outcome.as_ref().is_err_and(|e| e.contains("appears twice")));
+        assert_eq!(created, vec!["EP001_First", "EP002_Second"]);
+    }
+}
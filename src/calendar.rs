@@ -0,0 +1,234 @@
+use std::{fs, io}; // OS crate
+use std::path::{Path, PathBuf}; // File path crate
+use chrono::{Datelike, Duration, Months, NaiveDate}; // Calendar days, weeks and months
+use serde::{Deserialize, Serialize}; // The status is stored in the project's metadata
+
+use crate::{DateKind, ProjectType};
+use crate::ics::{self, IcsEvent};
+use crate::metadata::{self, ProjectMeta};
+use crate::mlt;
+use crate::premiere;
+use crate::undo;
+
+const SCHEDULE_FILE: &str = "Content_calendar.ics"; // Exported schedule inside the base folder
+
+// How far along a project is on its way to being published
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum ProjectStatus {
+    #[default]
+    Planned, // Folder made, nothing recorded yet
+    Filming, // Recording the A-roll and B-roll
+    Editing, // Cutting the video
+    Scheduled, // Uploaded and set to go live on the publish date
+    Published, // Live
+}
+
+impl ProjectStatus {
+    pub const ALL: [ProjectStatus; 5] = [ProjectStatus::Planned, ProjectStatus::Filming, ProjectStatus::Editing, ProjectStatus::Scheduled, ProjectStatus::Published]; // Choices shown in the calendar
+
+    // Purpose: Gets the status' display name
+    pub fn label(&self) -> &'static str {
+        match self {
+            ProjectStatus::Planned => "Planned",
+            ProjectStatus::Filming => "Filming",
+            ProjectStatus::Editing => "Editing",
+            ProjectStatus::Scheduled => "Scheduled",
+            ProjectStatus::Published => "Published",
+        }
+    }
+}
+
+/*
+Purpose: Finds the projects that can go on the content calendar (everything except School semesters)
+Args: base_dir (Path) - base folder projects are created in
+Return: Project paths with their metadata, those with a publish date first in date order, then the unscheduled ones by path
+*/
+pub fn calendar_projects(base_dir: &Path) -> Vec<(PathBuf, ProjectMeta)> {
+    let mut projects: Vec<(PathBuf, ProjectMeta)> = metadata::list_projects(base_dir).into_iter().filter(|(_, meta)| meta.project_type != ProjectType::School).collect();
+    projects.sort_by_key(|(_, meta)| (meta.publish_date.is_none(), meta.publish_date));
+    projects
+}
+
+/*
+Purpose: Points the editor projects in a renamed project's Save folder at the new folder
+Args: project (Path) - project folder after the rename
+      from (Path) - project folder before the rename
+Return: Paths of the rewritten files relative to the project, using '/'
+*/
+fn move_editor_projects(project: &Path, from: &Path) -> io::Result<Vec<String>> {
+    let Ok(entries) = fs::read_dir(project.join("Save")) else {
+        return Ok(Vec::new());
+    };
+    let (from, to) = (std::path::absolute(from)?, std::path::absolute(project)?);
+
+    let mut moved = Vec::new();
+    for path in entries.filter_map(|e| e.ok().map(|e| e.path())).filter(|p| p.is_file()) {
+        let extension = path.extension().map(|e| e.to_string_lossy().to_lowercase()).unwrap_or_default();
+        match extension.as_str() {
+            "prproj" => premiere::move_project_paths(&path, &from, &to)?,
+            "kdenlive" | "mlt" => mlt::move_project_paths(&path, &from, &to)?,
+            _ => continue,
+        }
+        moved.push(format!("Save/{}", path.file_name().unwrap_or_default().to_string_lossy()));
+    }
+    Ok(moved)
+}
+
+/*
+Purpose: Moves a project to another publish date and/or status, a folder named after its publish date is renamed to the new date
+Args: base_dir (Path) - base folder, Shorts cut from a renamed project are pointed at its new folder
+      project (Path) - project folder
+      publish_date (Date/None) - new publish date, None takes it off the calendar
+      status (ProjectStatus) - new status
+Return: Project folder after the move (renamed if its name starts with the publish date)
+*/
+pub fn reschedule(base_dir: &Path, project: &Path, publish_date: Option<NaiveDate>, status: ProjectStatus) -> io::Result<PathBuf> {
+    let mut meta = ProjectMeta::load(project)?;
+    meta.publish_date = publish_date;
+    meta.status = status;
+
+    // Taking a project off the calendar keeps the folder named after the date it last had
+    let old_prefix = meta.date.format("%Y-%m-%d").to_string();
+    let new_date = publish_date.filter(|date| meta.date_kind == DateKind::Publish && *date != meta.date && meta.name.starts_with(&old_prefix));
+    let Some(date) = new_date else {
+        meta.save(project)?;
+        return Ok(project.to_path_buf());
+    };
+
+    let name = format!("{}{}", date.format("%Y-%m-%d"), &meta.name[old_prefix.len()..]);
+    let target = project.with_file_name(&name);
+    if target.exists() {
+        return Err(io::Error::new(io::ErrorKind::AlreadyExists, format!("Folder '{}' already exists", name)));
+    }
+
+    // Files not touched since creation stay undoable after their paths are rewritten
+    let changed = undo::changed_entries(project, &meta.files)?;
+    let old_path = meta.relative_path();
+    fs::rename(project, &target)?;
+    meta.name = name;
+    meta.date = date;
+
+    let moved = move_editor_projects(&target, project)?;
+    let current = undo::snapshot(&target)?;
+    for record in meta.files.iter_mut().filter(|r| moved.contains(&r.path) && !changed.contains(&r.path)) {
+        if let Some(new_record) = current.iter().find(|r| r.path == record.path) {
+            *record = new_record.clone();
+        }
+    }
+    meta.save(&target)?;
+
+    // Shorts cut from the project name it by its folder
+    for (short, mut short_meta) in metadata::list_projects(base_dir) {
+        if short_meta.parent.as_deref() == Some(old_path.as_str()) {
+            short_meta.parent = Some(meta.relative_path());
+            short_meta.save(&short)?;
+        }
+    }
+
+    Ok(target)
+}
+
+// Purpose: Gets the Monday of the week a day is in
+pub fn week_start(day: NaiveDate) -> NaiveDate {
+    day - Duration::days(day.weekday().num_days_from_monday() as i64)
+}
+
+// Purpose: Gets the seven days of the week a day is in, Monday first
+pub fn week_days(day: NaiveDate) -> Vec<NaiveDate> {
+    let start = week_start(day);
+    (0..7).map(|i| start + Duration::days(i)).collect()
+}
+
+/*
+Purpose: Lays out the month a day is in as full weeks
+Args: day (Date) - any day of the month
+Return: Weeks (Monday first) from the week of the 1st to the week of the last day, days of the next and previous months included
+*/
+pub fn month_weeks(day: NaiveDate) -> Vec<Vec<NaiveDate>> {
+    let first = day.with_day(1).unwrap_or(day);
+    let next_month = first + Months::new(1);
+
+    let mut weeks = Vec::new();
+    let mut start = week_start(first);
+    while start < next_month {
+        weeks.push(week_days(start));
+        start += Duration::days(7);
+    }
+    weeks
+}
+
+/*
+Purpose: Writes every project with a publish date into an iCalendar file in the base folder
+Args: base_dir (Path) - base folder projects are created in
+Return: Path of the written file and number of events in it
+*/
+pub fn export_schedule(base_dir: &Path) -> io::Result<(PathBuf, usize)> {
+    let events: Vec<IcsEvent> = calendar_projects(base_dir)
+        .into_iter()
+        .filter_map(|(_, meta)| {
+            Some(IcsEvent {
+                uid: format!("{}@y_template", meta.relative_path()).replace(' ', "_"),
+                date: meta.publish_date?,
+                summary: format!("{} ({})", meta.name, meta.status.label()),
+                description: format!("{} project\nStatus: {}\nFolder: {}", meta.project_type.label(), meta.status.label(), meta.relative_path()),
+            })
+        })
+        .collect();
+
+    let path = base_dir.join(SCHEDULE_FILE);
+    ics::write_calendar(&path, "Content calendar", &events)?;
+    Ok((path, events.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read; // Reads the rewritten Premiere project
+
+    // Purpose: Writes the metadata of a project folder named after its publish date
+    fn publish_project(base: &Path, name: &str, project_type: ProjectType, date: NaiveDate) -> PathBuf {
+        let project = base.join(name);
+        fs::create_dir_all(project.join("Save")).unwrap();
+        let mut meta = ProjectMeta::new(name, project_type);
+        (meta.date, meta.date_kind, meta.publish_date) = (date, DateKind::Publish, Some(date));
+        meta.files = undo::snapshot(&project).unwrap();
+        meta.save(&project).unwrap();
+        project
+    }
+
+    #[test]
+    fn renames_a_folder_named_after_its_publish_date() {
+        let base = std::env::temp_dir().join(format!("y_template_calendar_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&base);
+        let (old, new) = (NaiveDate::from_ymd_opt(2026, 1, 10).unwrap(), NaiveDate::from_ymd_opt(2026, 1, 17).unwrap());
+        let project = publish_project(&base, "2026-01-10_Drone", ProjectType::Youtube, old);
+        let template = Path::new(env!("CARGO_MANIFEST_DIR")).join("Assets").join("template.prproj");
+        let profile = ProjectType::Youtube.default_video_profile();
+        premiere::write_project(&template, &project.join("Save").join("Drone.prproj"), &project, &[], &profile).unwrap();
+        let mut meta = ProjectMeta::load(&project).unwrap();
+        meta.files = undo::snapshot(&project).unwrap();
+        meta.save(&project).unwrap();
+        let short = publish_project(&base, "2026-01-12_Drone cut", ProjectType::Shorts, old + Duration::days(2));
+        let mut short_meta = ProjectMeta::load(&short).unwrap();
+        short_meta.parent = Some("2026-01-10_Drone".to_string());
+        short_meta.save(&short).unwrap();
+
+        let moved = reschedule(&base, &project, Some(new), ProjectStatus::Editing);
+        let target = base.join("2026-01-17_Drone");
+        let (meta, short_meta) = (ProjectMeta::load(&target), ProjectMeta::load(&short).unwrap());
+        let project_left = project.exists();
+        let changed = meta.as_ref().map(|meta| undo::changed_entries(&target, &meta.files).unwrap());
+        let mut xml = String::new();
+        flate2::read::GzDecoder::new(fs::File::open(target.join("Save").join("Drone.prproj")).unwrap()).read_to_string(&mut xml).unwrap();
+        let _ = fs::remove_dir_all(&base);
+
+        assert_eq!(moved.unwrap(), target);
+        assert!(!project_left);
+        assert!(changed.unwrap().is_empty(), "the renamed project can still be undone");
+        assert!(xml.contains(&target.join("Save").join("Auto-Save").to_string_lossy().to_string()));
+        assert!(!xml.contains("2026-01-10_Drone"));
+        let meta = meta.unwrap();
+        assert_eq!((meta.name.as_str(), meta.date, meta.publish_date, meta.status), ("2026-01-17_Drone", new, Some(new), ProjectStatus::Editing));
+        assert_eq!(short_meta.parent.as_deref(), Some("2026-01-17_Drone"));
+    }
+}
@@ -0,0 +1,164 @@
+use std::collections::HashMap; // Option values by name
+use std::path::PathBuf; // File path crate
+use chrono::NaiveDate; // Dates given with --date
+
+use crate::config::Config;
+use crate::hooks;
+use crate::metadata;
+use crate::podcast::{self, PodcastInfo};
+use crate::school::SchoolInfo;
+use crate::tags::TagFilter;
+use crate::{DateKind, NewProject, ProjectType};
+
+const USAGE: &str = "Usage:
+  y_template                                   Opens the GUI
+  y_template create <name> --type <youtube|shorts|series|podcast|school> --base <folder>
+                    [--date YYYY-MM-DD] [--date-kind <created|publish>]
+  y_template list --base <folder> [--tag \"sponsor collab|tutorial -cs101\"] [--type youtube]";
+
+// Arguments split into positional values and --name value options
+struct Args {
+    positional: Vec<String>, // Values that are not options, in order
+    options: HashMap<String, String>, // Options given as --name value
+}
+
+impl Args {
+    // Purpose: Splits raw arguments into positional values and options
+    fn parse(raw: &[String]) -> Result<Self, String> {
+        let mut positional = Vec::new();
+        let mut options = HashMap::new();
+        let mut iter = raw.iter();
+
+        while let Some(arg) = iter.next() {
+            if let Some(name) = arg.strip_prefix("--") {
+                let value = iter.next().ok_or(format!("Missing value for --{}", name))?;
+                options.insert(name.to_string(), value.clone());
+            } else {
+                positional.push(arg.clone());
+            }
+        }
+
+        Ok(Args { positional, options })
+    }
+
+    // Purpose: Gets an option value
+    fn get(&self, name: &str) -> Option<&str> {
+        self.options.get(name).map(|v| v.as_str())
+    }
+}
+
+// Purpose: Connects the output to the terminal the tool was started from, the Windows build is a GUI app without a console of its own
+#[cfg(windows)]
+fn attach_console() {
+    const ATTACH_PARENT_PROCESS: u32 = u32::MAX; // Console of the process that started the tool
+
+    #[link(name = "kernel32")]
+    unsafe extern "system" {
+        fn AttachConsole(process_id: u32) -> i32;
+    }
+
+    // Started without a terminal (e.g. from Explorer) there is nothing to attach to and output is dropped
+    unsafe {
+        AttachConsole(ATTACH_PARENT_PROCESS);
+    }
+}
+
+// Purpose: Other systems keep the terminal's output as it is
+#[cfg(not(windows))]
+fn attach_console() {}
+
+/*
+Purpose: Runs the command line version of the tool
+Args: raw (String list) - arguments after the program name
+Return: Process exit code
+*/
+pub fn run(raw: &[String]) -> i32 {
+    attach_console();
+
+    let result = Args::parse(raw).and_then(|args| match args.positional.first().map(|s| s.as_str()) {
+        Some("create") => create(&args),
+        Some("list") => list(&args),
+        Some("help") => {
+            println!("{}", USAGE);
+            Ok(())
+        }
+        _ => Err(format!("Unknown command\n{}", USAGE)),
+    });
+
+    match result {
+        Ok(()) => 0,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            1
+        }
+    }
+}
+
+// Purpose: Gets the base folder given with --base
+fn base_dir(args: &Args) -> Result<PathBuf, String> {
+    let base = PathBuf::from(args.get("base").ok_or("Missing --base <folder>")?);
+    if !base.is_dir() {
+        return Err(format!("Base folder '{}' does not exist", base.to_string_lossy()));
+    }
+    Ok(base)
+}
+
+// Purpose: Reads a YYYY-MM-DD date option
+fn date_option(args: &Args, name: &str) -> Result<Option<NaiveDate>, String> {
+    args.get(name)
+        .map(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").map_err(|_| format!("Bad date '{}' (use YYYY-MM-DD)", date)))
+        .transpose()
+}
+
+// Purpose: Creates one project, the same way the GUI does
+fn create(args: &Args) -> Result<(), String> {
+    let name = args.positional.get(1).ok_or("Missing project name")?;
+    let type_name = args.get("type").ok_or("Missing --type")?;
+    let project_type = ProjectType::from_name(type_name).ok_or(format!("Unknown project type '{}'", type_name))?;
+    let base = base_dir(args)?;
+
+    let mut project = NewProject::new(name, project_type);
+    if let Some(date) = date_option(args, "date")? {
+        project.date = date;
+    }
+    if let Some(kind) = args.get("date-kind") {
+        project.date_kind = DateKind::from_name(kind).ok_or(format!("Unknown date kind '{}'", kind))?;
+    }
+
+    // Podcast episodes are numbered after the ones already in the base folder, School semesters start without dates or courses
+    if project_type == ProjectType::Podcast {
+        project.podcast = Some(PodcastInfo::new(podcast::next_episode(&base), Vec::new()));
+    }
+    if project_type == ProjectType::School {
+        project.school = Some(SchoolInfo::new(None, None, Vec::new()));
+    }
+
+    let path = project.create(&base).map_err(|e| e.to_string())?;
+    println!("Created {}", path.to_string_lossy());
+
+    // A failing hook is reported but the project is kept
+    let config = Config::load(&base).map_err(|e| e.to_string())?;
+    for line in hooks::run_hooks(&base, &path, project.project_type, &config) {
+        println!("{}", line);
+    }
+    Ok(())
+}
+
+// Purpose: Prints the projects in the base folder, filtered by tags and type
+fn list(args: &Args) -> Result<(), String> {
+    let base = base_dir(args)?;
+    let filter = TagFilter::parse(args.get("tag").unwrap_or_default());
+    let project_type = match args.get("type") {
+        Some(name) => Some(ProjectType::from_name(name).ok_or(format!("Unknown project type '{}'", name))?),
+        None => None,
+    };
+
+    for (_, meta) in metadata::list_projects(&base) {
+        if !filter.matches(&meta.tags) || project_type.is_some_and(|t| t != meta.project_type) {
+            continue;
+        }
+        let tags = if meta.tags.is_empty() { String::new() } else { format!("  [{}]", meta.tags.join(", ")) };
+        println!("{}  {}{}", meta.relative_path(), meta.project_type.label(), tags);
+    }
+    Ok(())
+}
@@ -0,0 +1,160 @@
+use std::{fs, io}; // OS crate
+use std::collections::BTreeMap; // Sorted key/value storage
+use std::path::{Path, PathBuf}; // File path crate
+use serde::{Deserialize, Serialize}; // Reads and writes the settings as JSON
+
+use crate::ProjectType;
+use crate::timeline;
+use crate::hooks::Hook;
+use crate::library::{LibraryEntry, LinkMode};
+use crate::license;
+use crate::metadata;
+use crate::mlt::VideoProfile;
+
+const CONFIG_FILE: &str = "config.json"; // Settings file inside the tool's data folder
+
+// Video editor a project type's "Save" folder gets a project file for
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum Editor {
+    #[default]
+    Premiere, // Adobe Premiere Pro (.prproj)
+    Kdenlive, // Kdenlive (.kdenlive)
+    Shotcut, // Shotcut (.mlt)
+}
+
+impl Editor {
+    pub const ALL: [Editor; 3] = [Editor::Premiere, Editor::Kdenlive, Editor::Shotcut]; // Choices shown in the settings
+
+    // Purpose: Gets the editor's display name
+    pub fn label(&self) -> &'static str {
+        match self {
+            Editor::Premiere => "Premiere Pro",
+            Editor::Kdenlive => "Kdenlive",
+            Editor::Shotcut => "Shotcut",
+        }
+    }
+
+    // Purpose: Gets the file extension of the editor's project files
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Editor::Premiere => "prproj",
+            Editor::Kdenlive => "kdenlive",
+            Editor::Shotcut => "mlt",
+        }
+    }
+}
+
+// How a project path is written when it is copied to the clipboard
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum CopyFormat {
+    #[default]
+    Plain, // C:\Videos\2026-01-31_Name
+    Quoted, // "C:\Videos\2026-01-31_Name", safe to paste into a terminal
+    FileUri, // file:///C:/Videos/2026-01-31_Name
+}
+
+impl CopyFormat {
+    pub const ALL: [CopyFormat; 3] = [CopyFormat::Plain, CopyFormat::Quoted, CopyFormat::FileUri]; // Choices shown in the settings
+
+    // Purpose: Gets the format's display name
+    pub fn label(&self) -> &'static str {
+        match self {
+            CopyFormat::Plain => "Plain path",
+            CopyFormat::Quoted => "Quoted path",
+            CopyFormat::FileUri => "File URI",
+        }
+    }
+
+    // Purpose: Writes a path in this format
+    pub fn format(&self, path: &Path) -> String {
+        match self {
+            CopyFormat::Plain => path.to_string_lossy().to_string(),
+            CopyFormat::Quoted => format!("\"{}\"", path.to_string_lossy()),
+            CopyFormat::FileUri => timeline::file_uri(path),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    pub student_name: String, // Used in submission zip names
+    pub submission_pattern: String, // Default zip name for submissions, see submission::zip_name
+    pub submission_excludes: Vec<String>, // File and folder names left out of submissions (* and ? wildcards)
+    pub premiere_import_bins: bool, // Adds A-roll and B-roll bins to new Premiere projects
+    pub editors: BTreeMap<ProjectType, Editor>, // Editor chosen for each project type (Premiere if not set)
+    pub video_profiles: BTreeMap<ProjectType, VideoProfile>, // Sequence resolution and frame rate of each project type (the type's default if not set)
+    pub podcast_url: String, // Web folder episode audio is uploaded to, used for RSS enclosures
+    pub library_dir: String, // Shared asset library, relative to the base folder or absolute (library::DEFAULT_LIBRARY_DIR if empty)
+    pub link_mode: LinkMode, // How library assets are placed into new projects
+    pub library_entries: Vec<LibraryEntry>, // Tagged library assets placed into new projects of each type
+    pub licensed_folders: Vec<String>, // Project folders holding music and stock footage that need a license recorded
+    pub hooks: Vec<Hook>, // Commands run after a project of a type is created, in order
+    pub openers: BTreeMap<String, String>, // Command opening each file extension ("folder" for folders), the system default if not set
+    pub copy_on_create: bool, // Copies the new project's path to the clipboard once it is created
+    pub copy_format: CopyFormat, // How copied project paths are written
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            student_name: String::new(),
+            submission_pattern: "{student}_{course}_{assignment}".to_string(),
+            submission_excludes: vec![
+                ".git".to_string(),
+                "*.tmp".to_string(),
+                "~$*".to_string(),
+                ".DS_Store".to_string(),
+                "Thumbs.db".to_string(),
+            ],
+            premiere_import_bins: false,
+            editors: BTreeMap::new(),
+            video_profiles: BTreeMap::new(),
+            podcast_url: String::new(),
+            library_dir: String::new(),
+            link_mode: LinkMode::default(),
+            library_entries: Vec::new(),
+            licensed_folders: license::DEFAULT_LICENSED_FOLDERS.iter().map(|f| f.to_string()).collect(),
+            hooks: Vec::new(),
+            openers: BTreeMap::new(),
+            copy_on_create: true,
+            copy_format: CopyFormat::default(),
+        }
+    }
+}
+
+// Purpose: Gets the path of the settings file for a base folder
+fn config_path(base_dir: &Path) -> PathBuf {
+    metadata::app_dir(base_dir).join(CONFIG_FILE)
+}
+
+impl Config {
+    // Purpose: Reads the settings of a base folder, using defaults if there are none yet
+    pub fn load(base_dir: &Path) -> io::Result<Self> {
+        match fs::read_to_string(config_path(base_dir)) {
+            Ok(contents) => Ok(serde_json::from_str(&contents)?),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Config::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    // Purpose: Gets the editor new projects of a type are set up for
+    pub fn editor(&self, project_type: ProjectType) -> Editor {
+        self.editors.get(&project_type).copied().unwrap_or_default()
+    }
+
+    // Purpose: Gets the resolution and frame rate of a type's editing projects, which clips are checked against
+    pub fn video_profile(&self, project_type: ProjectType) -> VideoProfile {
+        self.video_profiles
+            .get(&project_type)
+            .filter(|p| p.frame_rate_num > 0 && p.frame_rate_den > 0) // A hand-edited zero would divide by zero
+            .copied()
+            .unwrap_or(project_type.default_video_profile())
+    }
+
+    // Purpose: Writes the settings of a base folder
+    pub fn save(&self, base_dir: &Path) -> io::Result<()> {
+        fs::create_dir_all(metadata::app_dir(base_dir))?;
+        fs::write(config_path(base_dir), serde_json::to_string_pretty(self)?)
+    }
+}
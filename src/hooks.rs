@@ -0,0 +1,120 @@
+use std::{fs, io}; // OS crate
+use std::io::Write; // Appends to the log file
+use std::path::Path; // File path crate
+use std::process::Command; // Runs the hook commands
+use chrono::Local; // Time stamps in the log
+use serde::{Deserialize, Serialize}; // Stored in the settings
+
+use crate::ProjectType;
+use crate::config::Config;
+use crate::metadata::{self, ProjectMeta};
+use crate::undo;
+
+const LOG_FILE: &str = "hooks.log"; // Output of every hook run, inside the tool's data folder
+
+// Command run after a project of a type is created
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct Hook {
+    pub project_type: ProjectType, // Projects the hook runs for
+    pub command: String, // Shell command, run inside the new project folder
+}
+
+// Purpose: Builds the cmd command for one hook, passed as written since cmd does not read Rust's \" escapes
+#[cfg(windows)]
+fn shell(command: &str) -> Command {
+    use std::os::windows::process::CommandExt; // Adds arguments without quoting them
+
+    // With /S cmd only strips the outer quotes, so "C:\Program Files\app.exe" "arg" keeps its own quotes
+    let mut shell = Command::new("cmd");
+    shell.raw_arg(format!("/S /C \"{}\"", command));
+    shell
+}
+
+// Purpose: Builds the sh command for one hook
+#[cfg(not(windows))]
+fn shell(command: &str) -> Command {
+    let mut shell = Command::new("sh");
+    shell.arg("-c").arg(command);
+    shell
+}
+
+// Purpose: Appends one hook run to the log in the tool's data folder
+fn write_log(base_dir: &Path, text: &str) -> io::Result<()> {
+    fs::create_dir_all(metadata::app_dir(base_dir))?;
+    let mut log = fs::OpenOptions::new().create(true).append(true).open(metadata::app_dir(base_dir).join(LOG_FILE))?;
+    log.write_all(text.as_bytes())
+}
+
+/*
+Purpose: Runs the settings' hooks for a new project one after another, a failing hook does not stop the others
+Args: base_dir (Path) - base folder holding the settings and the log
+      project (Path) - new project folder, passed as PROJECT_PATH and used as the working folder
+      project_type (ProjectType) - type of the new project, picks the hooks and is passed as PROJECT_TYPE
+      config (Config) - settings holding the hooks
+Return: One result line per hook run, the full output goes to the log
+*/
+pub fn run_hooks(base_dir: &Path, project: &Path, project_type: ProjectType, config: &Config) -> Vec<String> {
+    let name = project.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+    // Not canonicalized, Windows would give \\?\C:\... paths that cmd cannot use as its working folder
+    let full_path = std::path::absolute(project).unwrap_or(project.to_path_buf());
+    let base_path = std::path::absolute(base_dir).unwrap_or(base_dir.to_path_buf());
+    let hooks: Vec<&Hook> = config.hooks.iter().filter(|h| h.project_type == project_type && !h.command.trim().is_empty()).collect();
+    let mut report = Vec::new();
+
+    // Taken first so only entries the hooks add are recorded, edits made while they run still count as changes
+    let before = if hooks.is_empty() { Ok(Vec::new()) } else { undo::snapshot(project) };
+
+    for hook in &hooks {
+        let output = shell(&hook.command)
+            .current_dir(&full_path)
+            .env("PROJECT_PATH", &full_path)
+            .env("PROJECT_NAME", &name)
+            .env("PROJECT_TYPE", project_type.label())
+            .env("BASE_PATH", &base_path)
+            .output();
+
+        let mut log = format!("[{}] {} {}: {}\n", Local::now().format("%Y-%m-%d %H:%M:%S"), project_type.label(), name, hook.command);
+        match output {
+            Ok(output) => {
+                log.push_str(&String::from_utf8_lossy(&output.stdout));
+                log.push_str(&String::from_utf8_lossy(&output.stderr));
+                log.push_str(&format!("{}\n\n", output.status));
+                if output.status.success() {
+                    report.push(format!("Hook '{}' finished", hook.command));
+                } else {
+                    report.push(format!("Hook '{}' failed ({}), see {}", hook.command, output.status, LOG_FILE));
+                }
+            }
+            Err(e) => {
+                log.push_str(&format!("could not start: {}\n\n", e));
+                report.push(format!("Hook '{}' could not start: {}", hook.command, e));
+            }
+        }
+
+        if let Err(e) = write_log(base_dir, &log) {
+            report.push(format!("Hook log could not be written: {}", e));
+        }
+    }
+
+    // Files the hooks made (e.g. a .git folder) count as part of the new project, so undo still works
+    if !hooks.is_empty() {
+        let snapshot = before.and_then(|before| {
+            let added: Vec<_> = undo::snapshot(project)?.into_iter().filter(|record| !before.iter().any(|r| r.path == record.path)).collect();
+            if added.is_empty() {
+                return Ok(());
+            }
+
+            // Read only now, after the slow walk, so edits the GUI saved while the hooks ran are kept
+            let mut meta = ProjectMeta::load(project)?;
+            let added: Vec<_> = added.into_iter().filter(|record| !meta.files.iter().any(|r| r.path == record.path)).collect();
+            meta.files.extend(added);
+            meta.files.sort_by(|a, b| a.path.cmp(&b.path));
+            meta.save(project)
+        });
+        if let Err(e) = snapshot {
+            report.push(format!("Project snapshot could not be updated: {}", e));
+        }
+    }
+
+    report
+}
@@ -0,0 +1,137 @@
+use std::{fs, io}; // OS crate
+use std::path::Path; // File path crate
+use chrono::{Duration, NaiveDate, Utc}; // Event days and the export time stamp
+
+// One all-day event in an exported calendar
+pub struct IcsEvent {
+    pub uid: String, // Stable id so calendar apps update events instead of duplicating them
+    pub date: NaiveDate, // Day of the event
+    pub summary: String, // Event title
+    pub description: String, // Event notes (may be empty)
+}
+
+// Purpose: Escapes text so commas, semicolons and new lines survive in an .ics value
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+// Purpose: Adds a content line, folding it so no line is longer than 75 bytes
+fn push_line(out: &mut String, line: &str) {
+    let mut width = 0;
+    for c in line.chars() {
+        if width + c.len_utf8() > 75 {
+            out.push_str("\r\n ");
+            width = 1;
+        }
+        out.push(c);
+        width += c.len_utf8();
+    }
+    out.push_str("\r\n");
+}
+
+/*
+Purpose: Builds the text of an iCalendar file
+Args: name (String) - calendar name shown by calendar apps
+      events (IcsEvent list) - all-day events to include
+Return: Calendar file contents
+*/
+pub fn calendar(name: &str, events: &[IcsEvent]) -> String {
+    let stamp = Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+    let mut out = String::new();
+
+    push_line(&mut out, "BEGIN:VCALENDAR");
+    push_line(&mut out, "VERSION:2.0");
+    push_line(&mut out, "PRODID:-//y_template//Project Folder Creator//EN");
+    push_line(&mut out, &format!("X-WR-CALNAME:{}", escape(name)));
+
+    for event in events {
+        push_line(&mut out, "BEGIN:VEVENT");
+        push_line(&mut out, &format!("UID:{}", escape(&event.uid)));
+        push_line(&mut out, &format!("DTSTAMP:{}", stamp));
+        push_line(&mut out, &format!("DTSTART;VALUE=DATE:{}", event.date.format("%Y%m%d")));
+        push_line(&mut out, &format!("DTEND;VALUE=DATE:{}", (event.date + Duration::days(1)).format("%Y%m%d")));
+        push_line(&mut out, &format!("SUMMARY:{}", escape(&event.summary)));
+        if !event.description.is_empty() {
+            push_line(&mut out, &format!("DESCRIPTION:{}", escape(&event.description)));
+        }
+        push_line(&mut out, "END:VEVENT");
+    }
+
+    push_line(&mut out, "END:VCALENDAR");
+    out
+}
+
+// Purpose: Writes an iCalendar file
+pub fn write_calendar(path: &Path, name: &str, events: &[IcsEvent]) -> io::Result<()> {
+    fs::write(path, calendar(name, events))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Purpose: Folds one line and splits the result back into its physical lines
+    fn folded(line: &str) -> Vec<String> {
+        let mut out = String::new();
+        push_line(&mut out, line);
+        assert!(out.ends_with("\r\n"));
+        out.trim_end_matches("\r\n").split("\r\n").map(|l| l.to_string()).collect()
+    }
+
+    // Purpose: Joins folded lines back together, dropping the space each continuation starts with
+    fn unfold(lines: &[String]) -> String {
+        lines.iter().enumerate().map(|(i, l)| if i == 0 { l.as_str() } else { &l[1..] }).collect()
+    }
+
+    #[test]
+    fn escapes_special_characters() {
+        assert_eq!(escape("a,b;c\\d\ne"), "a\\,b\\;c\\\\d\\ne");
+    }
+
+    #[test]
+    fn keeps_short_lines_whole() {
+        assert_eq!(folded("SUMMARY:Lab 1"), vec!["SUMMARY:Lab 1"]);
+        assert_eq!(folded(&"x".repeat(75)).len(), 1);
+    }
+
+    #[test]
+    fn folds_long_lines_at_75_octets() {
+        let line = format!("DESCRIPTION:{}", "a".repeat(200));
+        let lines = folded(&line);
+
+        assert!(lines.len() > 1);
+        assert!(lines.iter().all(|l| l.len() <= 75));
+        assert!(lines[1..].iter().all(|l| l.starts_with(' ')));
+        assert_eq!(lines[0].len(), 75);
+        assert_eq!(unfold(&lines), line);
+    }
+
+    #[test]
+    fn does_not_split_multibyte_characters() {
+        // 'é' is 2 bytes and '🎬' 4, so the 75 octet limit falls inside a character
+        let line = format!("SUMMARY:{}{}", "é".repeat(40), "🎬".repeat(30));
+        let lines = folded(&line);
+
+        assert!(lines.iter().all(|l| l.len() <= 75));
+        assert_eq!(unfold(&lines), line);
+    }
+
+    #[test]
+    fn writes_all_day_events() {
+        let event = IcsEvent {
+            uid: "lab-1@y_template".to_string(),
+            date: NaiveDate::from_ymd_opt(2026, 2, 28).unwrap(),
+            summary: "CS101: Lab 1".to_string(),
+            description: String::new(),
+        };
+        let text = calendar("Spring, 2026", &[event]);
+
+        assert!(text.contains("X-WR-CALNAME:Spring\\, 2026\r\n"));
+        assert!(text.contains("DTSTART;VALUE=DATE:20260228\r\nDTEND;VALUE=DATE:20260301\r\n"));
+        assert!(!text.contains("DESCRIPTION"));
+        assert!(text.starts_with("BEGIN:VCALENDAR\r\n") && text.ends_with("END:VCALENDAR\r\n"));
+    }
+}
@@ -0,0 +1,115 @@
+use std::{fs, io}; // OS crate
+use std::path::{Path, PathBuf}; // File path crate
+use std::time::SystemTime; // Modified times used to notice edits made in other apps
+
+// One line of the ideas file as the preview shows it
+#[derive(Debug, Clone, PartialEq)]
+pub enum Block {
+    Heading(usize, String), // Heading level (1 to 6) and text
+    Bullet(usize, String), // Indent level and item text
+    Text(String), // Ordinary line
+    Blank, // Empty line
+}
+
+// Purpose: Removes the markdown markers the preview does not draw (**, __, and \ escapes)
+fn plain(text: &str) -> String {
+    text.replace("**", "").replace("__", "").replace('\\', "").trim().to_string()
+}
+
+/*
+Purpose: Splits an ideas file into the lines the preview draws
+Args: markdown (String) - ideas file contents
+Return: One block per line, runs of empty lines kept as one
+*/
+pub fn parse_blocks(markdown: &str) -> Vec<Block> {
+    let mut blocks = Vec::new();
+
+    for line in markdown.lines() {
+        let trimmed = line.trim_start();
+        let level = trimmed.chars().take_while(|c| *c == '#').count();
+
+        let block = if trimmed.is_empty() {
+            if blocks.last() == Some(&Block::Blank) {
+                continue;
+            }
+            Block::Blank
+        } else if (1..=6).contains(&level) && trimmed[level..].starts_with(' ') {
+            Block::Heading(level, plain(&trimmed[level..]))
+        } else if let Some(item) = trimmed.strip_prefix("* ").or(trimmed.strip_prefix("- ")) {
+            Block::Bullet((line.len() - trimmed.len()) / 2, plain(item))
+        } else {
+            Block::Text(plain(line))
+        };
+        blocks.push(block);
+    }
+    blocks
+}
+
+// Purpose: Gets the modified time of a file, None if it cannot be read
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+// An ideas file open in the side panel
+#[derive(Debug, Clone)]
+pub struct IdeasFile {
+    pub path: PathBuf, // File being edited
+    pub text: String, // Contents in the editor
+    saved: String, // Contents last read from or written to the file
+    disk_modified: Option<SystemTime>, // Modified time of the file when it was last read or written
+    pub changed_outside: bool, // File was changed by another app while there were unsaved edits
+}
+
+impl IdeasFile {
+    // Purpose: Reads an ideas file into the editor
+    pub fn open(path: PathBuf) -> io::Result<Self> {
+        let text = fs::read_to_string(&path)?;
+        Ok(IdeasFile { disk_modified: modified_time(&path), saved: text.clone(), text, path, changed_outside: false })
+    }
+
+    // Purpose: Checks whether the editor has edits that are not written yet
+    pub fn is_dirty(&self) -> bool {
+        self.text != self.saved
+    }
+
+    // Purpose: Writes the editor contents into the file
+    pub fn save(&mut self) -> io::Result<()> {
+        fs::write(&self.path, &self.text)?;
+        self.saved = self.text.clone();
+        self.disk_modified = modified_time(&self.path);
+        self.changed_outside = false;
+        Ok(())
+    }
+
+    // Purpose: Replaces the editor contents with the file, dropping unsaved edits
+    pub fn reload(&mut self) -> io::Result<()> {
+        *self = IdeasFile::open(self.path.clone())?;
+        Ok(())
+    }
+
+    /*
+    Purpose: Looks for changes made to the file by another app
+    Args: None
+    Return: True if the file changed on disk, it is reloaded when there are no unsaved edits, otherwise changed_outside is set
+    */
+    pub fn check_disk(&mut self) -> io::Result<bool> {
+        let modified = modified_time(&self.path);
+        if modified == self.disk_modified || self.changed_outside {
+            return Ok(false);
+        }
+
+        // A write with the same contents (e.g. a save without edits) is not a change
+        let contents = fs::read_to_string(&self.path)?;
+        if contents == self.saved {
+            self.disk_modified = modified;
+            return Ok(false);
+        }
+
+        if self.is_dirty() {
+            self.changed_outside = true;
+        } else {
+            self.reload()?;
+        }
+        Ok(true)
+    }
+}
@@ -0,0 +1,77 @@
+use std::{fs, io}; // OS crate
+use std::path::Path; // File path crate
+use chrono::{Local, NaiveDate}; // Date an idea was written down
+use serde::{Deserialize, Serialize}; // The inbox is stored as JSON
+
+use crate::ProjectType;
+use crate::metadata;
+
+const INBOX_FILE: &str = "inbox.json"; // Ideas inbox inside the tool's data folder
+const NOTES_HEADING: &str = "##### **Inbox notes:**"; // Heading the notes of a promoted idea are written under
+
+// A video idea written down before it has a project folder
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InboxIdea {
+    pub title: String, // Becomes the project name when promoted
+    pub project_type: ProjectType, // Type of project it is promoted into
+    pub notes: String, // Free text copied into the project's ideas file
+
+    #[serde(default)]
+    pub added: Option<NaiveDate>, // When the idea was written down
+
+    #[serde(default)]
+    pub promoted: Option<String>, // Project it became, relative to the base folder (None while still an idea)
+}
+
+impl InboxIdea {
+    // Purpose: Creates an idea dated today
+    pub fn new(title: &str, project_type: ProjectType, notes: &str) -> Self {
+        InboxIdea {
+            title: title.trim().to_string(),
+            project_type,
+            notes: notes.trim().to_string(),
+            added: Some(Local::now().date_naive()),
+            promoted: None,
+        }
+    }
+}
+
+// Every idea of a base folder, in the order they were added
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Inbox {
+    #[serde(default)]
+    pub ideas: Vec<InboxIdea>, // Ideas, promoted ones are kept to show which project they became
+}
+
+impl Inbox {
+    // Purpose: Reads the ideas inbox of a base folder, empty if there is none yet
+    pub fn load(base_dir: &Path) -> io::Result<Self> {
+        match fs::read_to_string(metadata::app_dir(base_dir).join(INBOX_FILE)) {
+            Ok(contents) => Ok(serde_json::from_str(&contents)?),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Inbox::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    // Purpose: Writes the ideas inbox of a base folder
+    pub fn save(&self, base_dir: &Path) -> io::Result<()> {
+        fs::create_dir_all(metadata::app_dir(base_dir))?;
+        fs::write(metadata::app_dir(base_dir).join(INBOX_FILE), serde_json::to_string_pretty(self)?)
+    }
+}
+
+/*
+Purpose: Writes the notes of a promoted idea at the top of a new project's ideas file
+Args: project (Path) - new project folder
+      notes (String) - idea notes
+Return: Error message if failed (a project without an ideas file gets an Inbox_ideas.md)
+*/
+pub fn add_notes(project: &Path, notes: &str) -> io::Result<()> {
+    if notes.trim().is_empty() {
+        return Ok(());
+    }
+
+    let path = metadata::ideas_file(project).unwrap_or(project.join("Inbox_ideas.md"));
+    let existing = fs::read_to_string(&path).unwrap_or_default();
+    fs::write(&path, format!("{}\n\n{}\n\n\n\n{}", NOTES_HEADING, notes.trim(), existing))
+}
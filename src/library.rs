@@ -0,0 +1,211 @@
+use std::{fs, io}; // OS crate
+use std::collections::BTreeMap; // Sorted key/value storage
+use std::path::{Path, PathBuf}; // File path crate
+use serde::{Deserialize, Serialize}; // Tags and links are stored as JSON
+
+use crate::ProjectType;
+use crate::config::Config;
+use crate::metadata;
+
+pub const DEFAULT_LIBRARY_DIR: &str = "Asset Library"; // Library folder inside the base folder when none is set
+const INDEX_FILE: &str = "library.json"; // Asset tags inside the tool's data folder
+
+// How library assets are placed into new projects
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum LinkMode {
+    Symlink, // Link pointing at the library file (may need extra rights on Windows)
+    Hardlink, // Second name for the same file (library and projects must be on one drive)
+    #[default]
+    Copy, // Independent copy
+}
+
+impl LinkMode {
+    pub const ALL: [LinkMode; 3] = [LinkMode::Symlink, LinkMode::Hardlink, LinkMode::Copy]; // Choices shown in the settings
+
+    // Purpose: Gets the mode's display name
+    pub fn label(&self) -> &'static str {
+        match self {
+            LinkMode::Symlink => "Symbolic link",
+            LinkMode::Hardlink => "Hard link",
+            LinkMode::Copy => "Copy",
+        }
+    }
+}
+
+// Library assets of one tag placed into every new project of a type
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct LibraryEntry {
+    pub project_type: ProjectType, // Projects the entry applies to
+    pub tag: String, // Assets with this tag are placed
+    pub folder: String, // Folder inside the project they are placed in, using '/'
+}
+
+// A library asset placed into a project, stored in the project metadata
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LinkedAsset {
+    pub asset: String, // Path inside the library, using '/'
+    pub path: String, // Path inside the project, using '/'
+    pub mode: LinkMode, // How it was placed (Copy if a link could not be made)
+}
+
+// Tags of every library asset, stored in the tool's data folder
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LibraryIndex {
+    #[serde(default)]
+    pub tags: BTreeMap<String, Vec<String>>, // Asset path inside the library to its tags (lower case)
+}
+
+impl LibraryIndex {
+    // Purpose: Reads the asset tags of a base folder, empty if there are none yet
+    pub fn load(base_dir: &Path) -> io::Result<Self> {
+        match fs::read_to_string(metadata::app_dir(base_dir).join(INDEX_FILE)) {
+            Ok(contents) => Ok(serde_json::from_str(&contents)?),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(LibraryIndex::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    // Purpose: Writes the asset tags of a base folder
+    pub fn save(&self, base_dir: &Path) -> io::Result<()> {
+        fs::create_dir_all(metadata::app_dir(base_dir))?;
+        fs::write(metadata::app_dir(base_dir).join(INDEX_FILE), serde_json::to_string_pretty(self)?)
+    }
+
+    // Purpose: Sets the tags of one asset from comma separated text (no tags removes it)
+    pub fn set_tags(&mut self, asset: &str, text: &str) {
+        let tags: Vec<String> = text.split(',').map(|t| t.trim().to_lowercase()).filter(|t| !t.is_empty()).collect();
+        if tags.is_empty() {
+            self.tags.remove(asset);
+        } else {
+            self.tags.insert(asset.to_string(), tags);
+        }
+    }
+}
+
+/*
+Purpose: Gets the library folder of a base folder
+Args: base_dir (Path) - base folder projects are created in
+      config (Config) - settings, an empty library folder uses DEFAULT_LIBRARY_DIR in the base folder
+Return: Path to the library folder (may not exist)
+*/
+pub fn library_dir(base_dir: &Path, config: &Config) -> PathBuf {
+    if config.library_dir.trim().is_empty() {
+        base_dir.join(DEFAULT_LIBRARY_DIR)
+    } else {
+        base_dir.join(config.library_dir.trim())
+    }
+}
+
+// Purpose: Recursively collects the files under dir as paths relative to root
+fn walk(root: &Path, dir: &Path, files: &mut Vec<String>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for path in entries.filter_map(|e| e.ok().map(|e| e.path())) {
+        if path.is_dir() {
+            walk(root, &path, files);
+        } else {
+            files.push(path.strip_prefix(root).unwrap_or(&path).to_string_lossy().replace('\\', "/"));
+        }
+    }
+}
+
+// Purpose: Lists every file under a folder such as the library as '/' paths relative to it, sorted
+pub fn list_assets(library: &Path) -> Vec<String> {
+    let mut files = Vec::new();
+    walk(library, library, &mut files);
+    files.sort();
+    files
+}
+
+// Purpose: Places one file at target the given way, returning the way that worked
+fn place(source: &Path, target: &Path, mode: LinkMode) -> io::Result<LinkMode> {
+    // Symbolic links are resolved from the folder they are in, so they point at the full path
+    let source = &fs::canonicalize(source)?;
+    let linked = match mode {
+        #[cfg(unix)]
+        LinkMode::Symlink => std::os::unix::fs::symlink(source, target),
+        #[cfg(windows)]
+        LinkMode::Symlink => std::os::windows::fs::symlink_file(source, target),
+        #[cfg(not(any(unix, windows)))]
+        LinkMode::Symlink => Err(io::Error::from(io::ErrorKind::Unsupported)),
+        LinkMode::Hardlink => fs::hard_link(source, target),
+        LinkMode::Copy => fs::copy(source, target).map(|_| ()),
+    };
+
+    // Links can fail without admin rights or across drives, a copy still gives the project the asset
+    match linked {
+        Ok(()) => Ok(mode),
+        Err(_) if mode != LinkMode::Copy => fs::copy(source, target).map(|_| LinkMode::Copy),
+        Err(e) => Err(e),
+    }
+}
+
+/*
+Purpose: Places the library assets chosen by the settings' entries into a new project
+Args: base_dir (Path) - base folder projects are created in
+      project (Path) - new project folder
+      project_type (ProjectType) - type of the new project, picks the entries used
+      config (Config) - library folder, link mode and entries
+Return: Every asset placed, for the usage report
+*/
+pub fn link_into(base_dir: &Path, project: &Path, project_type: ProjectType, config: &Config) -> io::Result<Vec<LinkedAsset>> {
+    let entries: Vec<&LibraryEntry> = config.library_entries.iter().filter(|e| e.project_type == project_type).collect();
+    if entries.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let library = library_dir(base_dir, config);
+    let index = LibraryIndex::load(base_dir)?;
+    let mut linked = Vec::new();
+
+    for entry in entries {
+        let tag = entry.tag.trim().to_lowercase();
+        let folder = entry.folder.split('/').filter(|p| !p.is_empty()).fold(project.to_path_buf(), |path, part| path.join(part));
+
+        for (asset, _) in index.tags.iter().filter(|(_, tags)| tags.contains(&tag)) {
+            let source = asset.split('/').fold(library.clone(), |path, part| path.join(part));
+            let Some(file_name) = source.file_name() else {
+                continue;
+            };
+            if !source.is_file() {
+                continue; // Tagged asset was moved or deleted from the library
+            }
+
+            fs::create_dir_all(&folder)?;
+            let target = folder.join(file_name);
+            if target.exists() {
+                continue; // Another entry already placed it
+            }
+
+            let mode = place(&source, &target, config.link_mode)?;
+            linked.push(LinkedAsset {
+                asset: asset.clone(),
+                path: target.strip_prefix(project).unwrap_or(&target).to_string_lossy().replace('\\', "/"),
+                mode,
+            });
+        }
+    }
+
+    Ok(linked)
+}
+
+/*
+Purpose: Finds which projects use which library assets
+Args: base_dir (Path) - base folder projects are created in
+      config (Config) - library folder
+Return: Every library asset (and every recorded asset no longer in the library) with the projects using it
+*/
+pub fn usage_report(base_dir: &Path, config: &Config) -> BTreeMap<String, Vec<String>> {
+    let mut report: BTreeMap<String, Vec<String>> = list_assets(&library_dir(base_dir, config)).into_iter().map(|a| (a, Vec::new())).collect();
+
+    for (_, meta) in metadata::list_projects(base_dir) {
+        for linked in &meta.library {
+            let projects = report.entry(linked.asset.clone()).or_default();
+            if projects.last() != Some(&meta.relative_path()) {
+                projects.push(meta.relative_path()); // An asset placed in two folders counts once
+            }
+        }
+    }
+    report
+}
@@ -0,0 +1,187 @@
+use std::io; // OS crate
+use std::path::Path; // File path crate
+use chrono::{Local, NaiveDate}; // Date an entry was recorded
+use serde::{Deserialize, Serialize}; // Stored in the project metadata
+
+use crate::library;
+use crate::metadata::ProjectMeta;
+use crate::series;
+
+pub const DEFAULT_LICENSED_FOLDERS: [&str; 2] = ["Music", "B-roll/Stock"]; // Project folders checked when none are set (own B-roll is left out)
+
+// License an asset was brought into a project under
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum LicenseKind {
+    #[default]
+    Unknown, // Not looked up yet
+    OwnWork, // Recorded or made by the channel
+    Cc0, // Creative Commons Zero, no attribution needed
+    CcBy, // Creative Commons Attribution
+    CcBySa, // Creative Commons Attribution-ShareAlike
+    RoyaltyFree, // Bought or subscription stock (Artlist, Epidemic Sound, ...)
+    YoutubeAudioLibrary, // YouTube Audio Library track
+    Other, // Anything else, described in the attribution text
+}
+
+impl LicenseKind {
+    pub const ALL: [LicenseKind; 8] = [
+        LicenseKind::Unknown,
+        LicenseKind::OwnWork,
+        LicenseKind::Cc0,
+        LicenseKind::CcBy,
+        LicenseKind::CcBySa,
+        LicenseKind::RoyaltyFree,
+        LicenseKind::YoutubeAudioLibrary,
+        LicenseKind::Other,
+    ]; // Choices shown in the license form
+
+    // Purpose: Gets the license's display name
+    pub fn label(&self) -> &'static str {
+        match self {
+            LicenseKind::Unknown => "Unknown",
+            LicenseKind::OwnWork => "Own work",
+            LicenseKind::Cc0 => "CC0",
+            LicenseKind::CcBy => "CC BY 4.0",
+            LicenseKind::CcBySa => "CC BY-SA 4.0",
+            LicenseKind::RoyaltyFree => "Royalty free",
+            LicenseKind::YoutubeAudioLibrary => "YouTube Audio Library",
+            LicenseKind::Other => "Other",
+        }
+    }
+
+    // Purpose: Checks whether the license requires crediting the author
+    pub fn needs_attribution(&self) -> bool {
+        matches!(self, LicenseKind::CcBy | LicenseKind::CcBySa)
+    }
+}
+
+// One asset in a project's license ledger
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct LicenseEntry {
+    pub file: String, // Path inside the project, using '/'
+    pub source: String, // Where it came from (site, author or link)
+    pub license: LicenseKind, // License it is used under
+    pub attribution: String, // Credit line for the video description (empty if none is needed)
+
+    #[serde(default)]
+    pub added: Option<NaiveDate>, // When the entry was recorded
+}
+
+/*
+Purpose: Records the license of an asset in a project's ledger, replacing an earlier entry for the same file
+Args: project (Path) - project folder
+      entry (LicenseEntry) - asset and license, the file path is made relative to the project
+Return: Error message if failed
+*/
+pub fn add_entry(project: &Path, mut entry: LicenseEntry) -> io::Result<()> {
+    entry.file = entry.file.trim().replace('\\', "/").trim_start_matches('/').to_string();
+    if entry.file.is_empty() {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, "File cannot be empty"));
+    }
+    entry.added.get_or_insert(Local::now().date_naive());
+
+    let mut meta = ProjectMeta::load(project)?;
+    meta.licenses.retain(|e| e.file != entry.file);
+    meta.licenses.push(entry);
+    meta.licenses.sort_by(|a, b| a.file.cmp(&b.file));
+    meta.save(project)
+}
+
+// Purpose: Removes a file's entry from a project's ledger
+pub fn remove_entry(project: &Path, file: &str) -> io::Result<()> {
+    let mut meta = ProjectMeta::load(project)?;
+    meta.licenses.retain(|e| e.file != file);
+    meta.save(project)
+}
+
+/*
+Purpose: Gets the folders of a project checked for music and stock footage
+Args: meta (ProjectMeta) - project metadata, episodes of a series also check the series' shared music
+      folders (String list) - folders from the settings, relative to the project
+Return: Folder paths relative to the project, using '/'
+*/
+pub fn checked_folders(meta: &ProjectMeta, folders: &[String]) -> Vec<String> {
+    let mut checked: Vec<String> = folders
+        .iter()
+        .map(|f| f.trim().replace('\\', "/").trim_matches('/').to_string())
+        .filter(|f| !f.is_empty())
+        .collect();
+    if meta.series.is_some() {
+        checked.push(format!("../{}", series::MUSIC_FOLDER)); // Episodes sit inside their series folder
+    }
+    checked
+}
+
+/*
+Purpose: Finds the music and stock footage of a project that needs a license recorded
+Args: project (Path) - project folder
+      meta (ProjectMeta) - project metadata, assets placed from the asset library are included
+      folders (String list) - folders from the settings, see checked_folders
+Return: File paths relative to the project, sorted
+*/
+pub fn licensed_files(project: &Path, meta: &ProjectMeta, folders: &[String]) -> Vec<String> {
+    let mut files: Vec<String> = checked_folders(meta, folders)
+        .into_iter()
+        .flat_map(|folder| library::list_assets(&project.join(&folder)).into_iter().map(move |file| format!("{}/{}", folder, file)))
+        .chain(meta.library.iter().map(|linked| linked.path.clone()))
+        .collect();
+    files.sort();
+    files.dedup();
+    files
+}
+
+/*
+Purpose: Checks a project's ledger against its music and stock footage
+Args: project (Path) - project folder
+      meta (ProjectMeta) - project metadata holding the ledger
+      folders (String list) - folders from the settings, see checked_folders
+Return: One line per asset without a license, with an unknown license or missing its required credit
+*/
+pub fn warnings(project: &Path, meta: &ProjectMeta, folders: &[String]) -> Vec<String> {
+    let mut warnings: Vec<String> = licensed_files(project, meta, folders)
+        .into_iter()
+        .filter(|file| !meta.licenses.iter().any(|e| &e.file == file))
+        .map(|file| format!("{} has no license recorded", file))
+        .collect();
+
+    for entry in &meta.licenses {
+        if entry.license == LicenseKind::Unknown {
+            warnings.push(format!("{} has an unknown license", entry.file));
+        } else if entry.license.needs_attribution() && entry.attribution.trim().is_empty() && entry.source.trim().is_empty() {
+            warnings.push(format!("{} is {} but has no source or attribution to credit", entry.file, entry.license.label()));
+        }
+    }
+    warnings
+}
+
+// Purpose: Gets the credit line of one entry, written from its source when no attribution text was given
+fn credit(entry: &LicenseEntry) -> Option<String> {
+    let name = entry.file.rsplit('/').next().unwrap_or(&entry.file);
+    let name = name.rsplit_once('.').map(|(stem, _)| stem).unwrap_or(name);
+
+    if !entry.attribution.trim().is_empty() {
+        Some(entry.attribution.trim().to_string())
+    } else if entry.license.needs_attribution() && !entry.source.trim().is_empty() {
+        Some(format!("\"{}\" by {} ({})", name, entry.source.trim(), entry.license.label()))
+    } else {
+        None
+    }
+}
+
+/*
+Purpose: Builds the credits block to paste under the video description
+Args: entries (LicenseEntry list) - a project's ledger
+Return: "Credits:" followed by one line per credited asset, empty if nothing needs crediting
+*/
+pub fn attribution_block(entries: &[LicenseEntry]) -> String {
+    let mut lines: Vec<String> = Vec::new();
+    for line in entries.iter().filter_map(credit) {
+        if !lines.contains(&line) {
+            lines.push(line); // One credit for a track used in several places
+        }
+    }
+    if lines.is_empty() {
+        return String::new();
+    }
+    format!("Credits:\n{}", lines.join("\n"))
+}
@@ -1,24 +1,156 @@
 #![windows_subsystem = "windows"]
 
 use std::{fs, io}; // OS crate
+use std::collections::BTreeMap; // Sorted key/value storage
 use std::path::{Path, PathBuf}; // File path crate
-// use std::process::Command; // Allows the opening of applications
 use std::sync::{Arc, Mutex}; // Multitasking crate
-use chrono::Local; // Gets local time information from computer
+use chrono::{Datelike, Duration, Local, Months, NaiveDate}; // Gets local time information from computer
 use eframe::egui; // Allows for GUI interface
+use serde::{Deserialize, Serialize}; // Lets project types be stored in metadata
+
+mod batch;
+mod calendar;
+mod cli;
+mod config;
+mod hooks;
+mod ideas;
+mod inbox;
+mod ics;
+mod library;
+mod license;
+mod media;
+mod metadata;
+mod mlt;
+mod opener;
+mod podcast;
+mod premiere;
+mod psd;
+mod school;
+mod search;
+mod series;
+mod submission;
+mod tags;
+mod timeline;
+mod undo;
+mod upload;
+
+use calendar::ProjectStatus;
+use config::{Config, CopyFormat, Editor};
+use hooks::Hook;
+use ideas::{Block, IdeasFile};
+use inbox::{Inbox, InboxIdea};
+use library::{LibraryEntry, LibraryIndex, LinkMode};
+use license::{LicenseEntry, LicenseKind};
+use metadata::ProjectMeta;
+use mlt::VideoProfile;
+use podcast::PodcastInfo;
+use school::{Course, SchoolInfo};
+use search::{SearchHit, SearchIndex};
+use series::SeriesEpisode;
+use tags::TagFilter;
 
+#[derive(Default)]
 enum ScanStatus {
+    #[default]
     Idle,
     Scanning,
     Found(PathBuf),
     NotFound,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Serialize, Deserialize)]
 enum ProjectType {
+    #[default]
     None,
     Youtube,
     School,
+    Shorts, // Vertical video, may be cut from a Youtube project
+    Podcast, // Numbered audio episode
+    Series, // Folder of numbered Youtube or Shorts episodes sharing intro and branding assets
+}
+
+impl ProjectType {
+    const ALL: [ProjectType; 5] = [ProjectType::Youtube, ProjectType::Shorts, ProjectType::Series, ProjectType::Podcast, ProjectType::School]; // Types a project can be created as
+
+    // Purpose: Gets the type's display name
+    fn label(&self) -> &'static str {
+        match self {
+            ProjectType::Youtube => "Youtube",
+            ProjectType::School => "School",
+            ProjectType::Shorts => "Shorts",
+            ProjectType::Podcast => "Podcast",
+            ProjectType::Series => "Series",
+            ProjectType::None => "None",
+        }
+    }
+
+    // Purpose: Gets the subfolders every project of this type starts with
+    fn subfolders(&self) -> &'static [&'static str] {
+        match self {
+            ProjectType::Youtube | ProjectType::Shorts => &["A-roll", "B-roll", "Save", "Photoshop"],
+            ProjectType::Podcast => &["Audio", "Edit", "Export", "Artwork"], // Audio gets a track folder per speaker
+            ProjectType::Series => &series::SERIES_SUBFOLDERS, // Episodes are added next to the shared assets
+            ProjectType::School => &[], // Semester folder, courses are added inside it
+            ProjectType::None => &[],
+        }
+    }
+
+    // Purpose: Gets the resolution and frame rate the type's editing project starts with when none is set
+    fn default_video_profile(&self) -> VideoProfile {
+        match self {
+            ProjectType::Shorts => VideoProfile { width: 1080, height: 1920, frame_rate_num: 30, frame_rate_den: 1 },
+            _ => VideoProfile { width: 1920, height: 1080, frame_rate_num: 30, frame_rate_den: 1 },
+        }
+    }
+
+    // Purpose: Gets the Photoshop template name and the size of the thumbnail made when it is missing
+    fn thumbnail(&self) -> (&'static str, usize, usize) {
+        match self {
+            ProjectType::Shorts => ("template_shorts.psd", 1080, 1920),
+            _ => ("template.psd", 1280, 720),
+        }
+    }
+
+    // Purpose: Checks if the type's folder names start with the project date
+    fn is_dated(&self) -> bool {
+        matches!(self, ProjectType::Youtube | ProjectType::Shorts)
+    }
+
+    // Purpose: Reads a project type from text such as "youtube" (Not case sensitive)
+    fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "youtube" => Some(ProjectType::Youtube),
+            "school" => Some(ProjectType::School),
+            "shorts" | "short" => Some(ProjectType::Shorts),
+            "podcast" => Some(ProjectType::Podcast),
+            "series" => Some(ProjectType::Series),
+            _ => None,
+        }
+    }
+}
+
+// What the date in a project's folder name means
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+enum DateKind {
+    #[default]
+    Created, // Day the project was set up
+    Publish, // Planned shoot or publish day
+}
+
+impl DateKind {
+    // Purpose: Reads a date kind from text such as "publish" (Not case sensitive)
+    fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "created" => Some(DateKind::Created),
+            "publish" => Some(DateKind::Publish),
+            _ => None,
+        }
+    }
+}
+
+// Purpose: Gets the Premiere project new projects are made from
+fn prproj_template() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("Assets").join("template.prproj")
 }
 
 /*
@@ -30,159 +162,2168 @@ Return: base_dir (Path/None)
 fn get_base_dir(start: &Path, target_path: &str) -> Option<PathBuf> {
     let entries = fs::read_dir(start).ok()?; 
 
-    for entry in entries {
-        let entry = entry.ok()?;
-        let path = entry.path();
+    for entry in entries {
+        let entry = entry.ok()?;
+        let path = entry.path();
+
+        if path.is_dir() && let Some(name) = path.file_name() {
+            // If the selected folder is target_path (Not case sensitive) it is chosen as the main base folder
+            if name.to_string_lossy().eq_ignore_ascii_case(target_path) {
+                return Some(path);
+            }
+        }
+    }
+    None
+}
+
+/*
+Purpose: Creates a new project folder in a desired location with subfolders for dividing up your work flow.
+Args: base_dir (Path) - file location for desired folder
+      folder_name (String) - The new project's folder name in date_inputted name format
+      project_type (ProjectType) - Decides the subfolders and the editing project's resolution
+      config (Config) - Settings of the base folder
+      branding (Path/None) - Branding folder of the series an episode belongs to, its thumbnail template is used first
+Return: Error message if failed
+*/
+fn create_directory(base_dir: &Path, folder_name: &str, project_type: ProjectType, config: &Config, branding: Option<&Path>) -> io::Result<PathBuf> {
+    let main = base_dir.join(folder_name);
+
+    if main.exists() {
+        return Err(io::Error::new(io::ErrorKind::AlreadyExists, "Folder exists"));
+    }
+
+    // If there is no folder in the base_dir with folder_name, a new project foler is created
+    fs::create_dir(&main)?;
+
+    // A half-made folder is removed again so creating it can be retried
+    if let Err(e) = fill_directory(&main, folder_name, project_type, config, branding) {
+        let _ = fs::remove_dir_all(&main);
+        return Err(e);
+    }
+
+    Ok(main)
+}
+
+// Purpose: Gets the editor project file inside the Save folder of a project folder named "<date>_<name>"
+fn editor_project_file(main: &Path, folder_name: &str, editor: Editor) -> PathBuf {
+    let name = folder_name.split('_').nth(1).unwrap_or(folder_name);
+    main.join("Save").join(format!("{}.{}", name, editor.extension()))
+}
+
+/*
+Purpose: Fills a new project folder with its subfolders, editor project, thumbnail and ideas file
+Args: main (Path) - new project folder
+      folder_name (String) - The new project's folder name in date_inputted name format
+      project_type (ProjectType) - Decides the subfolders and the editing project's resolution
+      config (Config) - Settings of the base folder
+      branding (Path/None) - Branding folder of the series an episode belongs to
+Return: Error message if failed
+*/
+fn fill_directory(main: &Path, folder_name: &str, project_type: ProjectType, config: &Config, branding: Option<&Path>) -> io::Result<()> {
+    for sub in project_type.subfolders() {
+        fs::create_dir(main.join(sub))?; // Creates subfolders
+    }
+
+    if main.join("Save").exists(){
+        // Creates a save file for the editor chosen for this project type
+        let project_name: Vec<&str> = folder_name.split("_").collect();
+        let editor = config.editor(project_type);
+        let save_file = editor_project_file(main, folder_name, editor);
+        if editor == Editor::Premiere {
+            let bins: &[&str] = if config.premiere_import_bins { &["A-roll", "B-roll"] } else { &[] };
+            premiere::write_project(&prproj_template(), &save_file, main, bins, &config.video_profile(project_type))?;
+        } else {
+            mlt::write_project(editor, &save_file, main, &config.video_profile(project_type))?;
+        }
+
+        // Creates a photoshop save file, generating a thumbnail canvas if there is no template
+        // (a series' own Branding template comes first for its episodes)
+        let (psd_template_name, thumb_width, thumb_height) = project_type.thumbnail();
+        let psd_template = match branding.map(|folder| folder.join(psd_template_name)) {
+            Some(series_template) if series_template.exists() => series_template,
+            _ => PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("Assets").join(psd_template_name),
+        };
+        let psd_name = format!("{}.psd", project_name[1]);
+        let psd = main.join("Photoshop").join(psd_name);
+        if psd_template.exists() {
+            fs::copy(psd_template, psd)?;
+        } else {
+            psd::write_thumbnail(&psd, thumb_width, thumb_height, project_name[1])?;
+        }
+
+        // Creates a photoshop save file
+        let md_template = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("Assets").join("template.md");
+        let prefix = project_name[1].get(..3).unwrap_or(project_name[1]);
+        let md_name = format!("{}_ideas.md", prefix);
+        let md = main.join(md_name);
+        fs::copy(md_template, md)?;
+    }
+
+    Ok(())
+}
+
+// Everything needed to create one project folder
+struct NewProject {
+    name: String, // Project name typed by the user (no date)
+    project_type: ProjectType, // Decides the subfolders and naming
+    date: NaiveDate, // Date used for the folder name prefix
+    date_kind: DateKind, // Whether date is the created or the publish date
+    fields: BTreeMap<String, String>, // Extra values stored in the project metadata
+    school: Option<SchoolInfo>, // Semester dates and first courses for School projects
+    parent: Option<String>, // Folder name of the Youtube project a Shorts project is cut from
+    podcast: Option<PodcastInfo>, // Episode number and speakers for Podcast projects
+    series: Option<SeriesEpisode>, // Series the project is an episode of, created inside the series folder
+    notes: String, // Notes written at the top of the ideas file (from a promoted inbox idea)
+}
+
+impl NewProject {
+    // Purpose: Sets up a project of the given type dated today
+    fn new(name: &str, project_type: ProjectType) -> Self {
+        NewProject {
+            name: name.trim().to_string(),
+            project_type,
+            date: Local::now().date_naive(),
+            date_kind: DateKind::Created,
+            fields: BTreeMap::new(),
+            school: None,
+            parent: None,
+            podcast: None,
+            series: None,
+            notes: String::new(),
+        }
+    }
+
+    // Purpose: Gets the folder name the project is created with
+    fn folder_name(&self) -> String {
+        let name = match &self.series {
+            Some(episode) => format!("{} {}", episode.label(), self.name),
+            None => self.name.clone(),
+        };
+
+        if let Some(info) = &self.podcast {
+            info.folder_name(&name)
+        } else if self.project_type.is_dated() {
+            format!("{}_{}", self.date.format("%Y-%m-%d"), name)
+        } else {
+            name
+        }
+    }
+
+    /*
+    Purpose: Creates the project folder and records its metadata
+    Args: base_dir (Path) - base folder the project is placed in
+    Return: Path to the new project folder
+    */
+    fn create(&self, base_dir: &Path) -> io::Result<PathBuf> {
+        let final_name = self.folder_name();
+
+        // Checks the semester dates before anything is created
+        if let Some(SchoolInfo { start: Some(start), end: Some(end), .. }) = &self.school {
+            school::lecture_weeks(*start, *end)?;
+        }
+
+        // A Shorts project can only be linked to an existing long-form project
+        if let Some(parent) = &self.parent {
+            let parent_meta = ProjectMeta::load(&base_dir.join(parent))
+                .map_err(|_| io::Error::new(io::ErrorKind::NotFound, format!("Parent project '{}' not found", parent)))?;
+            if parent_meta.project_type != ProjectType::Youtube {
+                return Err(io::Error::new(io::ErrorKind::InvalidInput, format!("'{}' is not a Youtube project", parent)));
+            }
+        }
+
+        // Episodes go inside their series folder, the settings still come from the base folder
+        let parent_dir = match &self.series {
+            Some(episode) => {
+                let series_dir = base_dir.join(&episode.series);
+                if !ProjectMeta::load(&series_dir).is_ok_and(|meta| meta.project_type == ProjectType::Series) {
+                    return Err(io::Error::new(io::ErrorKind::NotFound, format!("Series '{}' not found", episode.series)));
+                }
+                series_dir
+            }
+            None => base_dir.to_path_buf(),
+        };
+
+        let config = Config::load(base_dir)?;
+        let branding = self.series.as_ref().map(|_| parent_dir.join("Branding"));
+        let created_path = create_directory(&parent_dir, &final_name, self.project_type, &config, branding.as_deref())?;
+
+        // The folder has no metadata until the last step, so Undo could not remove it if a step fails
+        if let Err(e) = self.fill(base_dir, &created_path, &final_name, &config) {
+            let _ = fs::remove_dir_all(&created_path);
+            return Err(e);
+        }
+
+        Ok(created_path)
+    }
+
+    /*
+    Purpose: Adds the type specific files to a new project folder and writes its metadata
+    Args: base_dir (Path) - base folder the project is created in
+          created_path (Path) - new project folder
+          final_name (String) - folder name of the project
+          config (Config) - settings of the base folder
+    Return: Error message if failed
+    */
+    fn fill(&self, base_dir: &Path, created_path: &Path, final_name: &str, config: &Config) -> io::Result<()> {
+        // Records what was created so the project can be safely undone
+        let mut meta = ProjectMeta::new(final_name, self.project_type);
+        meta.date = self.date;
+        meta.date_kind = self.date_kind;
+        meta.publish_date = (self.date_kind == DateKind::Publish).then_some(self.date);
+        meta.fields = self.fields.clone();
+        meta.parent = self.parent.clone();
+        meta.series = self.series.clone();
+
+        if let Some(info) = &self.school {
+            for course in &info.courses {
+                school::create_course_folders(created_path, course, info)?;
+            }
+            meta.school = Some(info.clone());
+        }
+
+        if let Some(info) = &self.podcast {
+            podcast::create_episode_files(created_path, &self.name, self.date, info)?;
+            meta.podcast = Some(info.clone());
+        }
+
+        inbox::add_notes(created_path, &self.notes)?;
+
+        // Shared library assets chosen in the settings for this type
+        meta.library = library::link_into(base_dir, created_path, self.project_type, config)?;
+
+        // Kdenlive and Shotcut projects list the clips in the footage folders, so they are written again with the linked ones
+        let editor = config.editor(self.project_type);
+        if !meta.library.is_empty() && editor != Editor::Premiere && created_path.join("Save").exists() {
+            let save_file = editor_project_file(created_path, final_name, editor);
+            mlt::write_project(editor, &save_file, created_path, &config.video_profile(self.project_type))?;
+        }
+
+        meta.files = undo::snapshot(created_path)?;
+        meta.save(created_path)
+    }
+}
+
+#[derive(Default)]
+struct MyApp {
+    search_folder_name: String, // Name for base_path that will be searched for
+    folder_name: String, // Name for new project folder
+    status: String, // Text shown to explain what is currently happening
+
+    base_path: Option<PathBuf>, // Path where new project folders are placed
+    project_path: PathBuf, // Path to new project folder created
+    project_type: ProjectType, // Indicates what type of project created
+    project_date: Option<NaiveDate>, // Date for the folder name (None uses today)
+    date_kind: DateKind, // Meaning of the date in the folder name
+    last_created: Option<PathBuf>, // Project that can still be undone
+    copy_pending: bool, // Copies the project path on the next frame (set once when a project is created)
+    project_files: Vec<(&'static str, PathBuf)>, // Ideas file, editor project and thumbnail of the last created project
+    hook_report: Arc<Mutex<Vec<String>>>, // Result of the post-create hooks of the last project, filled in by the hook thread
+
+    scan_status: Arc<Mutex<ScanStatus>>, // The current state of the application
+    pending_create: bool, // Indicator for if the base_path 
+
+    show_batch: bool, // Shows the batch creation window
+    batch_text: String, // CSV or list of project names to create
+    batch_all_or_nothing: bool, // Keeps nothing from a batch if any row fails
+    batch_report: Vec<String>, // Result line for every row of the last batch
+    batch_hook_report: Arc<Mutex<Vec<String>>>, // Result of the post-create hooks of the last batch, filled in by the hook thread
+
+    semester_start: Option<NaiveDate>, // First day of a new School semester
+    semester_end: Option<NaiveDate>, // Last day of a new School semester
+    courses_text: String, // Courses for a new semester, one "CODE Name" per line
+    show_school: bool, // Shows the school window
+    semesters: Vec<(PathBuf, ProjectMeta)>, // School projects found in the base folder
+    selected_semester: Option<PathBuf>, // Semester courses are added to
+    new_course: String, // Course typed in the school window
+    assignment_course: String, // Course code of a new assignment
+    assignment_title: String, // Title of a new assignment
+    assignment_due: Option<NaiveDate>, // Due date of a new assignment
+    assignment_pattern: String, // Folder pattern of new assignments (empty uses the semester's)
+    course_zip_pattern: String, // Submission zip pattern typed for the selected course
+
+    show_video: bool, // Shows the video window
+    video_projects: Vec<(PathBuf, ProjectMeta)>, // Projects in the base folder that have an A-roll folder
+    shorts_parent: Option<String>, // Youtube project a new Shorts project is cut from (path relative to the base folder)
+    series_projects: Vec<PathBuf>, // Series in the base folder
+    selected_series: Option<PathBuf>, // Series a new Youtube or Shorts project is added to as the next episode
+    speakers_text: String, // Hosts and guests of a new podcast episode, comma separated
+
+    show_podcast: bool, // Shows the podcast window
+    episodes: Vec<PathBuf>, // Podcast episodes in the base folder
+    selected_episode: Option<PathBuf>, // Episode the podcast window works on
+    selected_video: Option<PathBuf>, // Project the video window works on
+    clips_report: Vec<String>, // Result lines of the last timeline export, upload text or clip scan
+    clips: Vec<(&'static str, media::Clip, Vec<String>)>, // Footage folder, clip and settings mismatches of the selected project
+
+    show_library: bool, // Shows the asset library window
+    library_assets: Vec<(String, String, String, Vec<String>)>, // Library assets with their tags being edited (comma separated), the tags as last read and the projects using them
+    new_library_entry: LibraryEntry, // Entry being added in the library window
+
+    show_licenses: bool, // Shows the licenses window
+    license_projects: Vec<(PathBuf, ProjectMeta)>, // Projects music and stock footage licenses can be recorded for
+    selected_license_project: Option<PathBuf>, // Project the licenses window works on
+    unlicensed_files: Vec<String>, // Music and stock footage of the selected project with no license recorded
+    license_warnings: Vec<String>, // Ledger problems of the selected project
+    new_license: LicenseEntry, // Entry being typed in the licenses window
+
+    show_ideas: bool, // Shows the ideas side panel
+    ideas: Option<IdeasFile>, // Ideas file of the current project open in the side panel
+    ideas_preview: bool, // Shows the formatted ideas instead of the editor
+    ideas_edited_at: Option<f64>, // Time of the last edit not written yet, for autosave
+    ideas_checked_at: f64, // Time the ideas file was last checked for outside changes
+    ideas_close_failed: bool, // Saving the ideas failed when closing, the next close goes through without saving
+
+    show_inbox: bool, // Shows the ideas inbox window
+    inbox: Inbox, // Ideas written down before they have a project folder
+    selected_idea: Option<usize>, // Inbox idea being edited
+    new_idea_title: String, // Title typed for a new inbox idea
+
+    show_search: bool, // Shows the search window
+    search_query: String, // Words searched for in every project's notes
+    search_index: SearchIndex, // Cached text of every project's notes
+    search_hits: Vec<SearchHit>, // Projects matching the search
+    search_refreshed_at: Option<f64>, // Time the index was last brought up to date (None reloads it from disk)
+
+    show_projects: bool, // Shows the project browser window
+    all_projects: Vec<(PathBuf, ProjectMeta)>, // Every project in the base folder
+    tag_filter: String, // Tag combination the project browser is filtered by
+    selected_project: Option<PathBuf>, // Project whose tags are being edited
+    project_tags_text: String, // Tags of the selected project being edited, comma separated
+
+    show_calendar: bool, // Shows the content calendar window
+    calendar_projects: Vec<(PathBuf, ProjectMeta)>, // Projects that can be put on the calendar
+    calendar_day: NaiveDate, // Day whose month or week is shown
+    calendar_week: bool, // Shows a single week instead of the whole month
+    calendar_selected: Option<PathBuf>, // Project whose status is being edited
+
+    config: Config, // Settings stored in the base folder
+    show_settings: bool, // Shows the settings window
+    excludes_text: String, // Submission excludes being edited, comma separated
+    licensed_text: String, // Licensed asset folders being edited, comma separated
+    new_hook: Hook, // Hook being added in the settings window
+}
+
+// Ids of multi-line text boxes, Enter types a new line in them instead of creating a project
+const MULTILINE_TEXT_IDS: [&str; 4] = ["batch_text", "courses_text", "ideas_text", "inbox_notes"];
+const IDEAS_AUTOSAVE_SECONDS: f64 = 2.0; // Ideas edits are written once typing stops for this long
+const IDEAS_CHECK_SECONDS: f64 = 1.0; // How often the ideas file is checked for changes made in other apps
+const CALENDAR_CELL_WIDTH: f32 = 110.0; // Width of a day on the content calendar
+const SEARCH_REFRESH_SECONDS: f64 = 10.0; // How often the search index picks up changed notes while the search window is open
+
+impl MyApp {
+    // Purpose: Scans for base folder directory
+    fn start_scan(&mut self) { 
+        self.status = format!("Searching for {} folder",self.search_folder_name);
+
+        let scan_status = Arc::clone(&self.scan_status);
+        let target = self.search_folder_name.clone();
+
+        *scan_status.lock().unwrap() = ScanStatus::Scanning;
+
+        std::thread::spawn(move || {
+            let found = get_base_dir(Path::new("C:/"), &target);
+            
+            let mut status = scan_status.lock().unwrap();
+            *status = match found {
+                Some(path) => ScanStatus::Found(path),
+                None => ScanStatus::NotFound,
+            }
+        });
+    }
+
+    // Gets folder name and Project folder path
+    fn create_project(&mut self) {
+        if self.folder_name.trim().is_empty() {
+            self.status = "Folder name cannot be empty".to_string();
+            return;
+        }
+
+        if self.base_path.is_none() {
+            self.pending_create = true;
+            self.start_scan();
+            return;
+        }
+
+        self.finish_create_project();
+    }
+
+    // Purpose: Creates new project folder
+    fn finish_create_project(&mut self) {
+        let base_dir = self.base_path.as_ref().unwrap().clone();
+        let mut project = NewProject::new(&self.folder_name, self.project_type);
+        project.date = self.project_date.unwrap_or(project.date);
+        project.date_kind = self.date_kind;
+
+        if self.project_type == ProjectType::School {
+            let courses = self.courses_text.lines().filter_map(Course::parse).collect();
+            project.school = Some(SchoolInfo::new(self.semester_start, self.semester_end, courses));
+        }
+        if self.project_type == ProjectType::Shorts {
+            project.parent = self.shorts_parent.clone();
+        }
+        if matches!(self.project_type, ProjectType::Youtube | ProjectType::Shorts) && let Some(series_dir) = &self.selected_series {
+            project.series = Some(SeriesEpisode { series: folder_label(series_dir), episode: series::next_episode(series_dir) });
+        }
+        if self.project_type == ProjectType::Podcast {
+            project.podcast = Some(PodcastInfo::new(podcast::next_episode(&base_dir), podcast::parse_speakers(&self.speakers_text)));
+        }
+
+        match project.create(&base_dir) {
+            Ok(created_path) =>{
+                self.project_created(&base_dir, created_path, self.project_type);
+                self.pending_create = false;
+            } 
+            Err(e) => {
+                self.status = format!("Error: {}", e);
+                self.pending_create = false;
+            }
+        }
+    }
+
+    /*
+    Purpose: Shows a project that was just created and starts its hooks
+    Args: base_dir (Path) - base folder the project was created in
+          created_path (Path) - new project folder
+          project_type (ProjectType) - type of the new project
+    Return: None
+    */
+    fn project_created(&mut self, base_dir: &Path, created_path: PathBuf, project_type: ProjectType) {
+        self.status = "Folder created successfully".to_string();
+        self.project_path = created_path.clone();
+        self.project_files = opener::key_files(&created_path);
+        self.copy_pending = self.config.copy_on_create;
+        self.open_ideas(&created_path);
+        self.start_hooks(&self.hook_report, base_dir, vec![(created_path.clone(), project_type)]);
+        self.last_created = Some(created_path);
+        self.refresh_semesters();
+        self.refresh_video_projects();
+    }
+
+    /*
+    Purpose: Runs the post-create hooks of new projects in the background so slow commands do not freeze the window
+    Args: hook_report (Vec<String>) - report filled in by the hook thread
+          base_dir (Path) - base folder the projects were created in
+          projects (Vec<(PathBuf, ProjectType)>) - new project folders and their types, run one after another
+    Return: None
+    */
+    fn start_hooks(&self, hook_report: &Arc<Mutex<Vec<String>>>, base_dir: &Path, projects: Vec<(PathBuf, ProjectType)>) {
+        hook_report.lock().unwrap().clear();
+        // Lines are marked with the project they belong to when there is more than one
+        let labelled = projects.len() > 1;
+        let projects: Vec<(PathBuf, ProjectType)> = projects
+            .into_iter()
+            .filter(|(_, project_type)| self.config.hooks.iter().any(|h| h.project_type == *project_type))
+            .collect();
+        if projects.is_empty() {
+            return;
+        }
+
+        let (hook_report, base_dir, config) = (Arc::clone(hook_report), base_dir.to_path_buf(), self.config.clone());
+        *hook_report.lock().unwrap() = vec!["Running hooks...".to_string()];
+
+        std::thread::spawn(move || {
+            let mut report = Vec::new();
+            for (project, project_type) in &projects {
+                let lines = hooks::run_hooks(&base_dir, project, *project_type, &config);
+                if labelled {
+                    report.extend(lines.into_iter().map(|line| format!("{}: {}", folder_label(project), line)));
+                } else {
+                    report.extend(lines);
+                }
+            }
+            *hook_report.lock().unwrap() = report;
+        });
+    }
+
+    // Purpose: Writes unsaved ideas edits the autosave has not written yet, once, so a second close always goes through
+    fn save_ideas_before_close(&mut self) -> io::Result<()> {
+        let Some(ideas) = &mut self.ideas else {
+            return Ok(());
+        };
+        if !ideas.is_dirty() || ideas.changed_outside || self.ideas_close_failed {
+            return Ok(());
+        }
+
+        let result = ideas.save();
+        self.ideas_close_failed = result.is_err();
+        result
+    }
+
+    // Purpose: Writes the edits of the ideas file open in the side panel, unless it was changed in another app
+    fn save_open_ideas(&mut self) -> io::Result<()> {
+        match &mut self.ideas {
+            Some(ideas) if ideas.is_dirty() && !ideas.changed_outside => ideas.save(),
+            _ => Ok(()),
+        }
+    }
+
+    // Purpose: Opens a project's ideas file in the side panel, writing any edits of the file open before
+    fn open_ideas(&mut self, project: &Path) {
+        if let Err(e) = self.save_open_ideas() {
+            self.status = format!("Error: {}", e);
+            return;
+        }
+
+        self.ideas = metadata::ideas_file(project).and_then(|path| IdeasFile::open(path).ok());
+        self.ideas_edited_at = None;
+    }
+
+    // Purpose: Empties and hides the ideas side panel once its project is no longer the current one
+    fn close_ideas(&mut self) {
+        self.ideas = None;
+        self.ideas_edited_at = None;
+        self.ideas_close_failed = false;
+        self.show_ideas = false;
+    }
+
+    // Purpose: Draws the side panel editing the current project's ideas file
+    fn ideas_panel(&mut self, ctx: &egui::Context) {
+        if !self.show_ideas {
+            return;
+        }
+        let now = ctx.input(|i| i.time);
+
+        egui::SidePanel::right("ideas_panel").default_width(380.0).show(ctx, |ui| {
+            let Some(ideas) = &mut self.ideas else {
+                ui.label("Create a project to plan it here.");
+                return;
+            };
+
+            ui.horizontal(|ui| {
+                ui.strong(folder_label(&ideas.path));
+                ui.selectable_value(&mut self.ideas_preview, false, "Edit");
+                ui.selectable_value(&mut self.ideas_preview, true, "Preview");
+                if ui.add_enabled(ideas.is_dirty(), egui::Button::new("Save")).clicked() {
+                    match ideas.save() {
+                        Ok(()) => self.ideas_edited_at = None,
+                        Err(e) => self.status = format!("Error: {}", e),
+                    }
+                }
+            });
+
+            // Edits made in another app are loaded, unless they clash with edits made here
+            if now - self.ideas_checked_at > IDEAS_CHECK_SECONDS {
+                self.ideas_checked_at = now;
+                match ideas.check_disk() {
+                    Ok(true) if !ideas.changed_outside => self.status = "Ideas file reloaded, it was changed in another app".to_string(),
+                    Ok(_) => {}
+                    Err(e) => self.status = format!("Error: {}", e),
+                }
+            }
+            if ideas.changed_outside {
+                ui.colored_label(ui.visuals().warn_fg_color, "The file was changed in another app while you were editing it here.");
+                ui.horizontal(|ui| {
+                    if ui.button("Load Their Version").clicked() {
+                        if let Err(e) = ideas.reload() {
+                            self.status = format!("Error: {}", e);
+                        }
+                        self.ideas_edited_at = None;
+                    }
+                    if ui.button("Keep Mine").clicked() {
+                        if let Err(e) = ideas.save() {
+                            self.status = format!("Error: {}", e);
+                        }
+                        self.ideas_edited_at = None;
+                    }
+                });
+            }
+
+            ui.separator();
+            egui::ScrollArea::vertical().id_source("ideas_scroll").show(ui, |ui| {
+                if self.ideas_preview {
+                    for block in ideas::parse_blocks(&ideas.text) {
+                        match block {
+                            Block::Heading(level, text) => {
+                                ui.label(egui::RichText::new(text).strong().size(24.0 - 2.0 * level as f32));
+                            }
+                            Block::Bullet(indent, text) => {
+                                ui.horizontal_wrapped(|ui| {
+                                    ui.add_space(12.0 * indent as f32);
+                                    ui.label(format!("• {}", text));
+                                });
+                            }
+                            Block::Text(text) => {
+                                ui.label(text);
+                            }
+                            Block::Blank => ui.add_space(6.0),
+                        }
+                    }
+                } else {
+                    let editor = egui::TextEdit::multiline(&mut ideas.text)
+                        .id(egui::Id::new("ideas_text"))
+                        .desired_width(f32::INFINITY)
+                        .desired_rows(30);
+                    if ui.add(editor).changed() {
+                        self.ideas_edited_at = Some(now);
+                    }
+                }
+            });
+
+            // Autosave once typing stops, never over changes made in another app
+            if let Some(edited) = self.ideas_edited_at
+                && now - edited > IDEAS_AUTOSAVE_SECONDS
+                && !ideas.changed_outside
+            {
+                self.ideas_edited_at = None;
+                if let Err(e) = ideas.save() {
+                    self.status = format!("Error: {}", e);
+                }
+            }
+        });
+
+        // Keeps autosave and the outside change check running while nothing else redraws the window
+        ctx.request_repaint_after(std::time::Duration::from_secs_f64(IDEAS_CHECK_SECONDS));
+    }
+
+    // Purpose: Reads the ideas inbox of the base folder
+    fn refresh_inbox(&mut self) {
+        let Some(base_dir) = &self.base_path else {
+            return;
+        };
+
+        match Inbox::load(base_dir) {
+            Ok(inbox) => self.inbox = inbox,
+            Err(e) => self.status = format!("Error: {}", e),
+        }
+        if self.selected_idea.is_some_and(|i| i >= self.inbox.ideas.len()) {
+            self.selected_idea = None;
+        }
+    }
+
+    // Purpose: Writes the ideas inbox into the base folder
+    fn save_inbox(&mut self) {
+        let Some(base_dir) = &self.base_path else {
+            return;
+        };
+
+        if let Err(e) = self.inbox.save(base_dir) {
+            self.status = format!("Error: {}", e);
+        }
+    }
+
+    // Purpose: Creates a project from an inbox idea, its notes go into the new ideas file
+    fn promote_idea(&mut self, index: usize) {
+        let idea = self.inbox.ideas[index].clone();
+        if idea.project_type == ProjectType::None {
+            self.status = "Choose a project type for the idea first".to_string();
+            return;
+        }
+
+        let Some(base_dir) = self.base_path.clone() else {
+            return;
+        };
+
+        // Made from the idea alone, what is typed in the main window is left as it is
+        let mut project = NewProject::new(&idea.title, idea.project_type);
+        if idea.project_type == ProjectType::Podcast {
+            project.podcast = Some(PodcastInfo::new(podcast::next_episode(&base_dir), Vec::new()));
+        }
+        project.notes = idea.notes.clone();
+
+        match project.create(&base_dir) {
+            Ok(created_path) => {
+                let relative = created_path.strip_prefix(&base_dir).unwrap_or(&created_path).to_string_lossy().replace('\\', "/");
+                self.project_created(&base_dir, created_path, idea.project_type);
+                self.status = format!("Idea promoted to {}", relative);
+                self.inbox.ideas[index].promoted = Some(relative);
+                self.save_inbox();
+            }
+            Err(e) => self.status = format!("Error: {}", e),
+        }
+    }
+
+    // Purpose: Draws the ideas inbox window
+    fn inbox_window(&mut self, ctx: &egui::Context) {
+        let mut open = self.show_inbox;
+
+        egui::Window::new("Ideas Inbox").open(&mut open).show(ctx, |ui| {
+            let mut changed = false;
+
+            ui.horizontal(|ui| {
+                ui.add(egui::TextEdit::singleline(&mut self.new_idea_title).hint_text("Video idea"));
+                if ui.add_enabled(!self.new_idea_title.trim().is_empty(), egui::Button::new("Add Idea")).clicked() {
+                    let project_type = if self.project_type == ProjectType::None { ProjectType::Youtube } else { self.project_type };
+                    self.inbox.ideas.push(InboxIdea::new(&self.new_idea_title, project_type, ""));
+                    self.selected_idea = Some(self.inbox.ideas.len() - 1);
+                    self.new_idea_title.clear();
+                    changed = true;
+                }
+            });
+
+            // Every idea, the ones already made into projects show where they went
+            let mut promote = None;
+            egui::ScrollArea::vertical().id_source("inbox_ideas").max_height(200.0).show(ui, |ui| {
+                for (i, idea) in self.inbox.ideas.iter().enumerate() {
+                    ui.horizontal(|ui| {
+                        if ui.selectable_label(self.selected_idea == Some(i), &idea.title).clicked() {
+                            self.selected_idea = Some(i);
+                        }
+                        ui.weak(idea.project_type.label());
+                        match &idea.promoted {
+                            Some(project) => {
+                                ui.weak(format!("→ {}", project));
+                            }
+                            None => {
+                                if ui.small_button("Promote").clicked() {
+                                    promote = Some(i);
+                                }
+                            }
+                        }
+                    });
+                }
+            });
+
+            // Editor for the selected idea
+            if let Some(i) = self.selected_idea
+                && let Some(idea) = self.inbox.ideas.get_mut(i)
+            {
+                ui.separator();
+                ui.horizontal(|ui| {
+                    changed |= ui.text_edit_singleline(&mut idea.title).changed();
+                    egui::ComboBox::from_id_source("inbox_type")
+                        .selected_text(idea.project_type.label())
+                        .show_ui(ui, |ui| {
+                            for project_type in ProjectType::ALL {
+                                changed |= ui.selectable_value(&mut idea.project_type, project_type, project_type.label()).changed();
+                            }
+                        });
+                });
+                let notes = egui::TextEdit::multiline(&mut idea.notes)
+                    .id(egui::Id::new("inbox_notes"))
+                    .hint_text("Notes, copied into the ideas file when promoted")
+                    .desired_rows(5);
+                changed |= ui.add(notes).changed();
+
+                if ui.button("Remove Idea").clicked() {
+                    self.inbox.ideas.remove(i);
+                    self.selected_idea = None;
+                    changed = true;
+                }
+            }
+
+            if changed {
+                self.save_inbox();
+            }
+            if let Some(i) = promote {
+                self.promote_idea(i);
+            }
+        });
+
+        self.show_inbox = open;
+    }
+
+    // Purpose: Brings the search index up to date with the notes on disk and searches again
+    fn refresh_search_index(&mut self, now: f64) {
+        let Some(base_dir) = &self.base_path else {
+            return;
+        };
+
+        if self.search_refreshed_at.is_none() {
+            self.search_index = SearchIndex::load(base_dir).unwrap_or_default();
+        }
+        self.search_refreshed_at = Some(now);
+
+        // The index is only written when a file was read or dropped
+        let (read, removed) = self.search_index.refresh(base_dir);
+        if (read > 0 || removed > 0)
+            && let Err(e) = self.search_index.save(base_dir)
+        {
+            self.status = format!("Error: {}", e);
+        }
+        self.search_hits = self.search_index.search(&self.search_query);
+    }
+
+    // Purpose: Draws the search window for finding text in every project's notes
+    fn search_window(&mut self, ctx: &egui::Context) {
+        let mut open = self.show_search;
+        let now = ctx.input(|i| i.time);
+
+        if self.show_search && self.search_refreshed_at.is_none_or(|t| now - t > SEARCH_REFRESH_SECONDS) {
+            self.refresh_search_index(now);
+        }
+
+        egui::Window::new("Search").open(&mut open).show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                let search = ui.add(egui::TextEdit::singleline(&mut self.search_query).hint_text("drone \"sponsor read\""));
+                if search.changed() {
+                    self.search_hits = self.search_index.search(&self.search_query);
+                }
+                if ui.button("Refresh").clicked() {
+                    self.refresh_search_index(now);
+                }
+            });
+            ui.small(format!("{} notes indexed (.md and .txt files in every project)", self.search_index.files.len()));
+
+            // Matching projects with the matches highlighted
+            let mut open_path = None;
+            egui::ScrollArea::vertical().id_source("search_hits").max_height(350.0).show(ui, |ui| {
+                for hit in &self.search_hits {
+                    ui.separator();
+                    ui.strong(&hit.project);
+                    for (file, snippets) in &hit.files {
+                        ui.horizontal(|ui| {
+                            ui.label(file);
+                            if ui.small_button("Open").clicked() {
+                                open_path = self.base_path.as_ref().map(|base| base.join(&hit.project).join(file));
+                            }
+                        });
+                        for snippet in snippets {
+                            let normal = egui::TextFormat { color: ui.visuals().text_color(), ..Default::default() };
+                            let highlight = egui::TextFormat { background: ui.visuals().selection.bg_fill, ..normal.clone() };
+                            let mut job = egui::text::LayoutJob::default();
+                            job.append(&snippet.before, 0.0, normal.clone());
+                            job.append(&snippet.matched, 0.0, highlight);
+                            job.append(&snippet.after, 0.0, normal);
+                            ui.label(job);
+                        }
+                    }
+                }
+            });
+            if !self.search_query.trim().is_empty() && self.search_hits.is_empty() {
+                ui.label("No notes match");
+            }
+
+            if let Some(path) = open_path {
+                self.open_path(&path);
+            }
+        });
+
+        self.show_search = open;
+    }
+
+    // Purpose: Reads the projects shown on the content calendar
+    fn refresh_calendar(&mut self) {
+        let Some(base_dir) = &self.base_path else {
+            return;
+        };
+
+        self.calendar_projects = calendar::calendar_projects(base_dir);
+        if self.calendar_day == NaiveDate::default() {
+            self.calendar_day = Local::now().date_naive();
+        }
+    }
+
+    /*
+    Purpose: Moves a project to another publish date and/or status
+    Args: project (Path) - project folder
+          publish_date (Date/None) - new publish date, None takes it off the calendar
+          status (ProjectStatus) - new status
+    Return: None
+    */
+    fn reschedule_project(&mut self, project: &Path, publish_date: Option<NaiveDate>, status: ProjectStatus) {
+        let Some(base_dir) = self.base_path.clone() else {
+            return;
+        };
+        // The folder may be renamed, so edits of its ideas file are written first
+        if self.ideas.as_ref().is_some_and(|ideas| ideas.path.starts_with(project))
+            && let Err(e) = self.save_open_ideas()
+        {
+            self.status = format!("Error: ideas not saved, project not moved ({})", e);
+            return;
+        }
+
+        match calendar::reschedule(&base_dir, project, publish_date, status) {
+            Ok(moved) => {
+                self.status = match publish_date {
+                    Some(date) => format!("{} planned for {} ({})", folder_label(&moved), date, status.label()),
+                    None => format!("{} taken off the calendar", folder_label(&moved)),
+                };
+                if moved != project {
+                    self.project_moved(project, &moved);
+                    self.refresh_projects();
+                    self.refresh_video_projects();
+                }
+                self.refresh_calendar();
+            }
+            Err(e) => self.status = format!("Error: {}", e),
+        }
+    }
+
+    /*
+    Purpose: Points everything that refers to a project at its folder after it was renamed
+    Args: from (Path) - project folder before the rename
+          to (Path) - project folder after the rename
+    Return: None
+    */
+    fn project_moved(&mut self, from: &Path, to: &Path) {
+        for path in [Some(&mut self.project_path), self.last_created.as_mut(), self.selected_project.as_mut(), self.calendar_selected.as_mut()].into_iter().flatten() {
+            if path == from {
+                *path = to.to_path_buf();
+            }
+        }
+        if self.project_path == to {
+            self.project_files = opener::key_files(to);
+        }
+        if self.ideas.as_ref().is_some_and(|ideas| ideas.path.starts_with(from)) {
+            self.ideas = metadata::ideas_file(to).and_then(|path| IdeasFile::open(path).ok());
+        }
+    }
+
+    // Purpose: Writes the content calendar into an .ics file in the base folder
+    fn export_schedule(&mut self) {
+        let Some(base_dir) = &self.base_path else {
+            return;
+        };
+
+        self.status = match calendar::export_schedule(base_dir) {
+            Ok((path, count)) => format!("Exported {} publish dates to {}", count, path.to_string_lossy()),
+            Err(e) => format!("Error: {}", e),
+        };
+    }
+
+    // Purpose: Draws the content calendar, projects are dragged between days to reschedule them
+    fn calendar_window(&mut self, ctx: &egui::Context) {
+        let mut open = self.show_calendar;
+        let today = Local::now().date_naive();
+        let mut moved: Option<(PathBuf, Option<NaiveDate>)> = None;
+        let mut status_change: Option<(PathBuf, Option<NaiveDate>, ProjectStatus)> = None;
+        let mut open_path = None;
+
+        egui::Window::new("Calendar").open(&mut open).default_width(7.0 * (CALENDAR_CELL_WIDTH + 12.0)).show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                let day = self.calendar_day;
+                if ui.button("<").clicked() {
+                    self.calendar_day = if self.calendar_week { day - Duration::days(7) } else { day - Months::new(1) };
+                }
+                let title = if self.calendar_week {
+                    format!("Week of {}", calendar::week_start(day).format("%b %-d, %Y"))
+                } else {
+                    day.format("%B %Y").to_string()
+                };
+                ui.strong(title);
+                if ui.button(">").clicked() {
+                    self.calendar_day = if self.calendar_week { day + Duration::days(7) } else { day + Months::new(1) };
+                }
+                if ui.button("Today").clicked() {
+                    self.calendar_day = today;
+                }
+
+                ui.separator();
+                ui.radio_value(&mut self.calendar_week, false, "Month");
+                ui.radio_value(&mut self.calendar_week, true, "Week");
+
+                ui.separator();
+                if ui.button("Refresh").clicked() {
+                    self.refresh_calendar();
+                }
+                if ui.button("Export .ics").clicked() {
+                    self.export_schedule();
+                }
+            });
+            ui.horizontal_wrapped(|ui| {
+                for status in ProjectStatus::ALL {
+                    ui.colored_label(status_color(status), status.label());
+                }
+            });
+
+            let weeks = if self.calendar_week { vec![calendar::week_days(self.calendar_day)] } else { calendar::month_weeks(self.calendar_day) };
+            let cell_height = if self.calendar_week { 240.0 } else { 70.0 };
+
+            egui::ScrollArea::vertical().id_source("calendar").max_height(560.0).show(ui, |ui| {
+                egui::Grid::new("calendar_grid").num_columns(7).spacing([4.0, 4.0]).show(ui, |ui| {
+                    for name in ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"] {
+                        ui.strong(name);
+                    }
+                    ui.end_row();
+
+                    for week in weeks {
+                        for day in week {
+                            let (_, dropped) = ui.dnd_drop_zone::<PathBuf, ()>(egui::Frame::default().inner_margin(4.0), |ui| {
+                                ui.set_min_size(egui::vec2(CALENDAR_CELL_WIDTH, cell_height));
+                                ui.set_max_width(CALENDAR_CELL_WIDTH);
+                                ui.vertical(|ui| {
+                                    let number = egui::RichText::new(day.day().to_string());
+                                    if day == today {
+                                        ui.label(number.strong().underline());
+                                    } else if day.month() != self.calendar_day.month() && !self.calendar_week {
+                                        ui.label(number.weak());
+                                    } else {
+                                        ui.label(number);
+                                    }
+
+                                    for (project, meta) in self.calendar_projects.iter().filter(|(_, meta)| meta.publish_date == Some(day)) {
+                                        if calendar_entry(ui, project, meta, self.calendar_selected.as_ref() == Some(project)) {
+                                            self.calendar_selected = Some(project.clone());
+                                        }
+                                    }
+                                });
+                            });
+                            if let Some(project) = dropped {
+                                moved = Some(((*project).clone(), Some(day)));
+                            }
+                        }
+                        ui.end_row();
+                    }
+                });
+            });
+
+            // Projects without a publish date, dropping a planned one here takes it off the calendar
+            let (_, dropped) = ui.dnd_drop_zone::<PathBuf, ()>(egui::Frame::default().inner_margin(4.0), |ui| {
+                ui.set_min_width(ui.available_width());
+                ui.label("Unscheduled (drag onto a day to plan it):");
+                ui.horizontal_wrapped(|ui| {
+                    for (project, meta) in self.calendar_projects.iter().filter(|(_, meta)| meta.publish_date.is_none()) {
+                        if calendar_entry(ui, project, meta, self.calendar_selected.as_ref() == Some(project)) {
+                            self.calendar_selected = Some(project.clone());
+                        }
+                    }
+                });
+            });
+            if let Some(project) = dropped {
+                moved = Some(((*project).clone(), None));
+            }
+
+            if let Some(project) = &self.calendar_selected
+                && let Some((_, meta)) = self.calendar_projects.iter().find(|(p, _)| p == project)
+            {
+                ui.separator();
+                ui.horizontal(|ui| {
+                    ui.strong(meta.relative_path());
+                    ui.label(meta.publish_date.map(|d| d.to_string()).unwrap_or("No publish date".to_string()));
+
+                    let mut status = meta.status;
+                    egui::ComboBox::from_id_source("calendar_status").selected_text(status.label()).show_ui(ui, |ui| {
+                        for choice in ProjectStatus::ALL {
+                            ui.selectable_value(&mut status, choice, choice.label());
+                        }
+                    });
+                    if status != meta.status {
+                        status_change = Some((project.clone(), meta.publish_date, status));
+                    }
+                    if ui.button("Open Folder").clicked() {
+                        open_path = Some(project.clone());
+                    }
+                });
+            }
+        });
+
+        if let Some((project, publish_date)) = moved {
+            let status = self.calendar_projects.iter().find(|(p, _)| *p == project).map(|(_, meta)| meta.status).unwrap_or_default();
+            self.calendar_selected = Some(project.clone());
+            self.reschedule_project(&project, publish_date, status);
+        }
+        if let Some((project, publish_date, status)) = status_change {
+            self.reschedule_project(&project, publish_date, status);
+        }
+        if let Some(path) = open_path {
+            self.open_path(&path);
+        }
+
+        self.show_calendar = open;
+    }
+
+    // Purpose: Reads every project in the base folder for the project browser
+    fn refresh_projects(&mut self) {
+        let Some(base_dir) = &self.base_path else {
+            return;
+        };
+
+        self.all_projects = metadata::list_projects(base_dir);
+        if self.selected_project.as_ref().is_some_and(|s| !self.all_projects.iter().any(|(p, _)| p == s)) {
+            self.selected_project = None;
+            self.project_tags_text.clear();
+        }
+    }
+
+    // Purpose: Writes the tags typed for the selected project
+    fn save_project_tags(&mut self) {
+        let Some(project) = self.selected_project.clone() else {
+            return;
+        };
+
+        match tags::set_tags(&project, &tags::parse_tags(&self.project_tags_text)) {
+            Ok(()) => {
+                self.status = format!("Tags saved for {}", folder_label(&project));
+                self.refresh_projects();
+            }
+            Err(e) => self.status = format!("Error: {}", e),
+        }
+    }
+
+    // Purpose: Draws the project browser with tag filtering and editing
+    fn projects_window(&mut self, ctx: &egui::Context) {
+        let mut open = self.show_projects;
+
+        egui::Window::new("Projects").open(&mut open).show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                ui.add(egui::TextEdit::singleline(&mut self.tag_filter).hint_text("sponsor collab|tutorial -cs101"));
+                if ui.button("Refresh").clicked() {
+                    self.refresh_projects();
+                }
+            });
+            ui.small("Spaces mean and, | means or, - leaves out a tag.");
+
+            // Tags in use, clicking one adds it to the filter
+            ui.horizontal_wrapped(|ui| {
+                for tag in tags::all_tags(self.all_projects.iter().map(|(_, meta)| meta)) {
+                    if ui.small_button(&tag).clicked() {
+                        self.tag_filter = format!("{} {}", self.tag_filter.trim(), tag).trim().to_string();
+                    }
+                }
+            });
+
+            let filter = TagFilter::parse(&self.tag_filter);
+            let mut select = None;
+            egui::ScrollArea::vertical().id_source("projects").max_height(300.0).show(ui, |ui| {
+                egui::Grid::new("projects_grid").num_columns(4).striped(true).show(ui, |ui| {
+                    for heading in ["Project", "Type", "Date", "Tags"] {
+                        ui.strong(heading);
+                    }
+                    ui.end_row();
+
+                    for (project, meta) in self.all_projects.iter().filter(|(_, meta)| filter.matches(&meta.tags)) {
+                        if ui.selectable_label(self.selected_project.as_ref() == Some(project), meta.relative_path()).clicked() {
+                            select = Some((project.clone(), meta.tags.join(", ")));
+                        }
+                        ui.label(meta.project_type.label());
+                        ui.label(meta.date.to_string());
+                        ui.label(meta.tags.join(", "));
+                        ui.end_row();
+                    }
+                });
+            });
+            if let Some((project, tags)) = select {
+                self.selected_project = Some(project);
+                self.project_tags_text = tags;
+            }
+
+            if let Some(project) = self.selected_project.clone() {
+                ui.separator();
+                ui.horizontal(|ui| {
+                    ui.label(format!("Tags of {}:", folder_label(&project)));
+                    ui.add(egui::TextEdit::singleline(&mut self.project_tags_text).hint_text("sponsor, tutorial"));
+                    if ui.button("Save Tags").clicked() {
+                        self.save_project_tags();
+                    }
+                });
+            }
+        });
+
+        self.show_projects = open;
+    }
+
+    // Purpose: Opens a folder or file with the opener set for its type
+    fn open_path(&mut self, path: &Path) {
+        if let Err(e) = opener::open_path(path, &self.config) {
+            self.status = format!("Error: {}", e);
+        }
+    }
+
+    // Purpose: Creates every project listed in the batch text box
+    fn run_batch(&mut self) {
+        let Some(base_dir) = self.base_path.clone() else {
+            self.status = "Search for a base folder first".to_string();
+            return;
+        };
+
+        let today = Local::now().date_naive();
+        let mut rows = batch::parse(&self.batch_text, self.project_type, self.project_date.unwrap_or(today));
+        for project in rows.iter_mut().filter_map(|r| r.project.as_mut().ok()) {
+            project.date_kind = self.date_kind;
+        }
+        if rows.is_empty() {
+            self.status = "Batch is empty".to_string();
+            return;
+        }
+
+        let results = batch::run(&base_dir, rows, self.batch_all_or_nothing);
+        let created = results.iter().filter(|r| r.outcome.is_ok()).count();
+
+        self.batch_report = results
+            .iter()
+            .map(|r| match &r.outcome {
+                Ok(path) => format!("Line {}: created {}", r.line, path.to_string_lossy()),
+                Err(e) => format!("Line {}: '{}' failed - {}", r.line, r.name, e),
+            })
+            .collect();
+        self.status = format!("Batch finished: {} of {} projects created", created, results.len());
+
+        // Hooks only run for the projects that are kept, the same as when creating one project
+        let kept = results.iter().filter_map(|r| r.outcome.as_ref().ok().map(|path| (path.clone(), r.project_type))).collect();
+        self.start_hooks(&self.batch_hook_report, &base_dir, kept);
+    }
+
+    // Purpose: Draws the batch creation window
+    fn batch_window(&mut self, ctx: &egui::Context) {
+        let mut open = self.show_batch;
+
+        egui::Window::new("Batch create").open(&mut open).show(ctx, |ui| {
+            ui.label("Paste a CSV with a header (name, type, date, custom columns) or one project name per line.");
+            ui.label("Rows without a type use the type selected in the main window.");
+            ui.label("School rows can have start and end (YYYY-MM-DD) and courses (\"CS101 Intro; MATH201 Calculus\") columns.");
+            ui.add(
+                egui::TextEdit::multiline(&mut self.batch_text)
+                    .id(egui::Id::new("batch_text"))
+                    .hint_text("name,type,date\nMy video,youtube,2026-01-31")
+                    .desired_rows(6),
+            );
+            ui.checkbox(&mut self.batch_all_or_nothing, "All or nothing (keep no projects if a row fails)");
+
+            if ui.button("Create Batch").clicked() {
+                self.run_batch();
+            }
+
+            egui::ScrollArea::vertical().max_height(150.0).show(ui, |ui| {
+                for line in &self.batch_report {
+                    ui.label(line);
+                }
+                for line in self.batch_hook_report.lock().unwrap().iter() {
+                    ui.label(line);
+                }
+            });
+        });
+
+        self.show_batch = open;
+    }
+
+    // Purpose: Finds the School semesters in the base folder
+    fn refresh_semesters(&mut self) {
+        let Some(base_dir) = &self.base_path else {
+            return;
+        };
+
+        self.semesters = metadata::list_projects(base_dir)
+            .into_iter()
+            .filter(|(_, meta)| meta.project_type == ProjectType::School)
+            .collect();
+
+        if self.selected_semester.as_ref().is_none_or(|s| !self.semesters.iter().any(|(p, _)| p == s)) {
+            self.selected_semester = self.semesters.first().map(|(p, _)| p.clone());
+        }
+    }
+
+    // Purpose: Adds the typed course to the selected semester
+    fn add_course(&mut self) {
+        let Some(semester) = self.selected_semester.clone() else {
+            self.status = "Select a semester first".to_string();
+            return;
+        };
+        let Some(course) = Course::parse(&self.new_course) else {
+            self.status = "Enter a course as \"CODE Name\"".to_string();
+            return;
+        };
+
+        match school::add_course(&semester, &course) {
+            Ok(path) => {
+                self.status = format!("Course added: {}", path.to_string_lossy());
+                self.new_course.clear();
+                self.refresh_semesters();
+            }
+            Err(e) => self.status = format!("Error: {}", e),
+        }
+    }
+
+    // Purpose: Registers the typed assignment in the selected semester
+    fn add_assignment(&mut self) {
+        let Some(semester) = self.selected_semester.clone() else {
+            self.status = "Select a semester first".to_string();
+            return;
+        };
+
+        let due = self.assignment_due.unwrap_or(Local::now().date_naive());
+        // Only a pattern typed here replaces the semester's
+        let pattern = Some(self.assignment_pattern.trim()).filter(|p| !p.is_empty());
+
+        match school::add_assignment(&semester, &self.assignment_course, &self.assignment_title, due, pattern) {
+            Ok(path) => {
+                self.status = format!("Assignment added: {}", path.to_string_lossy());
+                self.assignment_title.clear();
+                self.refresh_semesters();
+            }
+            Err(e) => self.status = format!("Error: {}", e),
+        }
+    }
+
+    // Purpose: Exports the selected semester's assignments as a calendar file
+    fn export_assignments(&mut self) {
+        let Some(semester) = self.selected_semester.clone() else {
+            self.status = "Select a semester first".to_string();
+            return;
+        };
+
+        self.status = match school::export_assignments_ics(&semester) {
+            Ok(path) => format!("Calendar exported: {}", path.to_string_lossy()),
+            Err(e) => format!("Error: {}", e),
+        };
+    }
+
+    // Purpose: Zips an assignment folder for submission
+    fn bundle_submission(&mut self, folder: &str) {
+        let Some(semester) = self.selected_semester.clone() else {
+            self.status = "Select a semester first".to_string();
+            return;
+        };
+
+        match submission::bundle(&semester, folder, &self.config) {
+            Ok(path) => {
+                self.status = format!("Submission bundled: {}", path.to_string_lossy());
+                self.refresh_semesters();
+            }
+            Err(e) => self.status = format!("Error: {}", e),
+        }
+    }
+
+    // Purpose: Saves the typed submission zip pattern for the selected course
+    fn set_course_zip_pattern(&mut self) {
+        let Some(semester) = self.selected_semester.clone() else {
+            self.status = "Select a semester first".to_string();
+            return;
+        };
+
+        match school::set_submission_pattern(&semester, &self.assignment_course, &self.course_zip_pattern) {
+            Ok(()) => {
+                self.status = format!("Submission name saved for {}", self.assignment_course);
+                self.refresh_semesters();
+            }
+            Err(e) => self.status = format!("Error: {}", e),
+        }
+    }
+
+    // Purpose: Reads the settings stored in the base folder
+    fn load_config(&mut self) {
+        let Some(base_dir) = &self.base_path else {
+            return;
+        };
+
+        match Config::load(base_dir) {
+            Ok(config) => self.config = config,
+            Err(e) => {
+                self.status = format!("Settings could not be read, using defaults: {}", e);
+                self.config = Config::default();
+            }
+        }
+        self.excludes_text = self.config.submission_excludes.join(", ");
+        self.licensed_text = self.config.licensed_folders.join(", ");
+    }
+
+    // Purpose: Writes the settings into the base folder
+    fn save_config(&mut self) {
+        let Some(base_dir) = &self.base_path else {
+            return;
+        };
+
+        self.config.submission_excludes = self
+            .excludes_text
+            .split(',')
+            .map(|p| p.trim().to_string())
+            .filter(|p| !p.is_empty())
+            .collect();
+        self.config.licensed_folders = self
+            .licensed_text
+            .split(',')
+            .map(|f| f.trim().to_string())
+            .filter(|f| !f.is_empty())
+            .collect();
+        self.config.openers.retain(|_, command| !command.trim().is_empty());
+
+        self.status = match self.config.save(base_dir) {
+            Ok(()) => "Settings saved".to_string(),
+            Err(e) => format!("Error: {}", e),
+        };
+    }
+
+    // Purpose: Draws the settings window
+    fn settings_window(&mut self, ctx: &egui::Context) {
+        let mut open = self.show_settings;
+
+        egui::Window::new("Settings").open(&mut open).show(ctx, |ui| {
+            egui::Grid::new("settings_grid").num_columns(2).show(ui, |ui| {
+                ui.label("Student name:");
+                ui.text_edit_singleline(&mut self.config.student_name);
+                ui.end_row();
+
+                ui.label("Submission zip name:");
+                ui.text_edit_singleline(&mut self.config.submission_pattern);
+                ui.end_row();
+
+                ui.label("Leave out of submissions:");
+                ui.text_edit_singleline(&mut self.excludes_text);
+                ui.end_row();
+
+                ui.label("Podcast audio address:");
+                ui.add(egui::TextEdit::singleline(&mut self.config.podcast_url).hint_text("https://example.com/episodes"));
+                ui.end_row();
+
+                ui.label("Asset library folder:");
+                ui.add(egui::TextEdit::singleline(&mut self.config.library_dir).hint_text(library::DEFAULT_LIBRARY_DIR));
+                ui.end_row();
+
+                ui.label("Check for licenses in:");
+                ui.add(egui::TextEdit::singleline(&mut self.licensed_text).hint_text(license::DEFAULT_LICENSED_FOLDERS.join(", ")))
+                    .on_hover_text("Project folders holding music and stock footage, comma separated. Series episodes also check the series' Music folder.");
+                ui.end_row();
+
+                ui.label("Copy project paths as:");
+                ui.horizontal(|ui| {
+                    egui::ComboBox::from_id_source("copy_format")
+                        .selected_text(self.config.copy_format.label())
+                        .show_ui(ui, |ui| {
+                            for format in CopyFormat::ALL {
+                                ui.selectable_value(&mut self.config.copy_format, format, format.label());
+                            }
+                        });
+                    ui.checkbox(&mut self.config.copy_on_create, "Copy when a project is created");
+                });
+                ui.end_row();
+
+                ui.label("Place library assets as:");
+                egui::ComboBox::from_id_source("link_mode")
+                    .selected_text(self.config.link_mode.label())
+                    .show_ui(ui, |ui| {
+                        for mode in LinkMode::ALL {
+                            ui.selectable_value(&mut self.config.link_mode, mode, mode.label());
+                        }
+                    });
+                ui.end_row();
+            });
+            ui.small("Zip name fields: {student} {course} {assignment}. Leave-out names use * and ? wildcards.");
+
+            // Editor project written into the Save folder of each type that has one, with its sequence size and frame rate
+            ui.separator();
+            egui::Grid::new("editor_grid").num_columns(3).show(ui, |ui| {
+                for project_type in ProjectType::ALL.into_iter().filter(|t| t.subfolders().contains(&"Save")) {
+                    ui.label(format!("{} editor:", project_type.label()));
+                    // Only a choice the user changes is stored, untouched types keep following the built-in defaults
+                    let mut editor = self.config.editor(project_type);
+                    egui::ComboBox::from_id_source(("editor", project_type.label()))
+                        .selected_text(editor.label())
+                        .show_ui(ui, |ui| {
+                            for choice in Editor::ALL {
+                                ui.selectable_value(&mut editor, choice, choice.label());
+                            }
+                        });
+                    if editor != self.config.editor(project_type) {
+                        self.config.editors.insert(project_type, editor);
+                    }
+
+                    let mut profile = self.config.video_profile(project_type);
+                    ui.horizontal(|ui| {
+                        ui.add(egui::DragValue::new(&mut profile.width).clamp_range(16..=8192));
+                        ui.label("x");
+                        ui.add(egui::DragValue::new(&mut profile.height).clamp_range(16..=8192));
+                        let mut rate = (profile.frame_rate_num, profile.frame_rate_den);
+                        egui::ComboBox::from_id_source(("frame_rate", project_type.label()))
+                            .selected_text(format!("{} fps", profile.fps_label()))
+                            .show_ui(ui, |ui| {
+                                for (num, den) in mlt::FRAME_RATES {
+                                    let label = VideoProfile { frame_rate_num: num, frame_rate_den: den, ..profile }.fps_label();
+                                    ui.selectable_value(&mut rate, (num, den), format!("{} fps", label));
+                                }
+                            });
+                        (profile.frame_rate_num, profile.frame_rate_den) = rate;
+                    });
+                    if profile != self.config.video_profile(project_type) {
+                        self.config.video_profiles.insert(project_type, profile);
+                    }
+                    ui.end_row();
+                }
+            });
+
+            ui.checkbox(&mut self.config.premiere_import_bins, "Add A-roll and B-roll bins to new Premiere projects");
+
+            // Commands run inside every new project of a type
+            ui.separator();
+            ui.label("After creating a project run:");
+            let mut remove = None;
+            for (i, hook) in self.config.hooks.iter().enumerate() {
+                ui.horizontal(|ui| {
+                    ui.label(format!("{}: {}", hook.project_type.label(), hook.command));
+                    if ui.small_button("Remove").clicked() {
+                        remove = Some(i);
+                    }
+                });
+            }
+            if let Some(i) = remove {
+                self.config.hooks.remove(i);
+            }
+
+            ui.horizontal(|ui| {
+                egui::ComboBox::from_id_source("hook_type")
+                    .selected_text(self.new_hook.project_type.label())
+                    .show_ui(ui, |ui| {
+                        for project_type in ProjectType::ALL {
+                            ui.selectable_value(&mut self.new_hook.project_type, project_type, project_type.label());
+                        }
+                    });
+                ui.add(egui::TextEdit::singleline(&mut self.new_hook.command).hint_text("git init"));
+                if ui.add_enabled(!self.new_hook.command.trim().is_empty(), egui::Button::new("Add")).clicked() {
+                    let command = std::mem::take(&mut self.new_hook.command).trim().to_string();
+                    self.config.hooks.push(Hook { command, ..self.new_hook.clone() });
+                }
+            });
+            ui.small("Hooks get PROJECT_PATH, PROJECT_NAME, PROJECT_TYPE and BASE_PATH. Their output goes to .y_template/hooks.log.");
+
+            // Applications the Open buttons use, empty uses the system default
+            ui.separator();
+            egui::Grid::new("opener_grid").num_columns(2).show(ui, |ui| {
+                for key in opener::OPENER_KEYS {
+                    ui.label(if key == opener::FOLDER_KEY { "Open folders with:".to_string() } else { format!("Open .{} with:", key) });
+                    let command = self.config.openers.entry(key.to_string()).or_default();
+                    ui.add(egui::TextEdit::singleline(command).hint_text("System default"));
+                    ui.end_row();
+                }
+            });
+            ui.small("{path} is replaced by the file, otherwise it is added at the end. Quote programs with spaces in their path.");
+
+            if ui.button("Save Settings").clicked() {
+                self.save_config();
+            }
+        });
+
+        self.show_settings = open;
+    }
+
+    // Purpose: Finds the projects in the base folder that have footage folders
+    fn refresh_video_projects(&mut self) {
+        let Some(base_dir) = &self.base_path else {
+            return;
+        };
+
+        let projects = metadata::list_projects(base_dir);
+        self.series_projects = projects.iter().filter(|(_, meta)| meta.project_type == ProjectType::Series).map(|(p, _)| p.clone()).collect();
+        if self.selected_series.as_ref().is_some_and(|s| !self.series_projects.contains(s)) {
+            self.selected_series = None;
+        }
+        self.video_projects = projects.into_iter().filter(|(path, _)| path.join("A-roll").is_dir()).collect();
+
+        if self.selected_video.as_ref().is_none_or(|s| !self.video_projects.iter().any(|(p, _)| p == s)) {
+            self.selected_video = self.video_projects.last().map(|(p, _)| p.clone());
+        }
+    }
+
+    // Purpose: Writes FCPXML and OTIO timelines of the selected project's A-roll clips
+    fn export_timeline(&mut self) {
+        let Some(project) = self.selected_video.clone() else {
+            self.status = "Select a project first".to_string();
+            return;
+        };
+
+        match timeline::export(&project, &self.config) {
+            Ok((files, warnings)) => {
+                self.status = format!("Timeline exported to {}", folder_label(&project.join("Save")));
+                self.clips_report = files.iter().map(|f| format!("Wrote {}", folder_label(f))).chain(warnings).collect();
+            }
+            Err(e) => self.status = format!("Error: {}", e),
+        }
+    }
+
+    // Purpose: Writes upload.txt and upload.json from the Upload section of the selected project's ideas file
+    fn make_upload_text(&mut self) {
+        let Some(project) = self.selected_video.clone() else {
+            self.status = "Select a project first".to_string();
+            return;
+        };
+
+        match upload::write_upload(&project, &self.config.licensed_folders) {
+            Ok((files, warnings)) => {
+                self.status = format!("Upload text written, {} warnings", warnings.len());
+                self.clips_report = files.iter().map(|f| format!("Wrote {}", folder_label(f))).chain(warnings).collect();
+            }
+            Err(e) => self.status = format!("Error: {}", e),
+        }
+    }
+
+    // Purpose: Reads the clips of the selected project and compares them with its sequence settings
+    fn scan_clips(&mut self) {
+        let Some(project) = self.selected_video.clone() else {
+            self.status = "Select a project first".to_string();
+            return;
+        };
+
+        let project_type = ProjectMeta::load(&project).map(|meta| meta.project_type).unwrap_or(ProjectType::Youtube);
+        let profile = self.config.video_profile(project_type);
+        let (clips, errors) = media::project_clips(&project);
+        self.clips = clips
+            .into_iter()
+            .map(|(folder, clip)| {
+                let mismatches = clip.mismatches(&profile);
+                (folder, clip, mismatches)
+            })
+            .collect();
+
+        let flagged = self.clips.iter().filter(|(_, _, m)| !m.is_empty()).count();
+        self.status = format!("{} clips read, {} do not match the project's {}x{} {} fps", self.clips.len(), flagged, profile.width, profile.height, profile.fps_label());
+        self.clips_report = errors;
+    }
+
+    // Purpose: Draws the video window for the footage and upload text of existing projects
+    fn video_window(&mut self, ctx: &egui::Context) {
+        let mut open = self.show_video;
+
+        egui::Window::new("Video").open(&mut open).show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                let selected = self.selected_video.as_ref().map(|p| folder_label(p)).unwrap_or_default();
+                egui::ComboBox::from_label("Project")
+                    .selected_text(selected)
+                    .show_ui(ui, |ui| {
+                        for (project, _) in &self.video_projects {
+                            ui.selectable_value(&mut self.selected_video, Some(project.clone()), folder_label(project));
+                        }
+                    });
+
+                if ui.button("Refresh").clicked() {
+                    self.refresh_video_projects();
+                }
+            });
+
+            // Links between a long-form project and the Shorts cut from it
+            if let Some((_, meta)) = self.video_projects.iter().find(|(p, _)| Some(p) == self.selected_video.as_ref()) {
+                if let Some(parent) = &meta.parent {
+                    ui.label(format!("Cut from {}", parent));
+                }
+                let name = meta.relative_path();
+                let shorts: Vec<String> = self
+                    .video_projects
+                    .iter()
+                    .filter(|(_, m)| m.parent.as_deref() == Some(name.as_str()))
+                    .map(|(p, _)| folder_label(p))
+                    .collect();
+                if !shorts.is_empty() {
+                    ui.label(format!("Shorts: {}", shorts.join(", ")));
+                }
+            }
+
+            ui.horizontal(|ui| {
+                if ui.add_enabled(self.selected_video.is_some(), egui::Button::new("Read Clips")).clicked() {
+                    self.scan_clips();
+                }
+                if ui.add_enabled(self.selected_video.is_some(), egui::Button::new("Export Timeline")).clicked() {
+                    self.export_timeline();
+                }
+                if ui.add_enabled(self.selected_video.is_some(), egui::Button::new("Make Upload Text")).clicked() {
+                    self.make_upload_text();
+                }
+            });
+            ui.small("Export Timeline lists the A-roll clips in recording order for DaVinci Resolve (OTIO) or Final Cut (FCPXML).");
+            ui.small("Make Upload Text reads the Upload section of the ideas file into upload.txt and upload.json.");
+
+            // Clip table, clips that do not match the project settings in red
+            egui::ScrollArea::vertical().id_source("clips").max_height(200.0).show(ui, |ui| {
+                egui::Grid::new("clips_grid").num_columns(7).striped(true).show(ui, |ui| {
+                    for heading in ["Folder", "File", "Recorded", "Length", "Size", "FPS", "Codec"] {
+                        ui.strong(heading);
+                    }
+                    ui.end_row();
+
+                    for (folder, clip, mismatches) in &self.clips {
+                        let color = if mismatches.is_empty() { ui.visuals().text_color() } else { egui::Color32::RED };
+                        let cells = [
+                            folder.to_string(),
+                            clip.name(),
+                            clip.order_time().map(|t| t.format("%Y-%m-%d %H:%M").to_string()).unwrap_or_default(),
+                            clip.duration.map(media::format_duration).unwrap_or_default(),
+                            clip.width.zip(clip.height).map(|(w, h)| format!("{}x{}", w, h)).unwrap_or_default(),
+                            clip.fps.map(|fps| format!("{:.2}", fps)).unwrap_or_default(),
+                            clip.codec.clone().unwrap_or_default(),
+                        ];
+                        for cell in cells {
+                            let label = ui.colored_label(color, cell);
+                            if !mismatches.is_empty() {
+                                label.on_hover_text(mismatches.join("\n"));
+                            }
+                        }
+                        ui.end_row();
+                    }
+                });
+            });
+
+            for line in &self.clips_report {
+                ui.label(line);
+            }
+        });
+
+        self.show_video = open;
+    }
+
+    // Purpose: Finds the podcast episodes in the base folder
+    fn refresh_episodes(&mut self) {
+        let Some(base_dir) = &self.base_path else {
+            return;
+        };
+
+        self.episodes = metadata::list_projects(base_dir)
+            .into_iter()
+            .filter(|(_, meta)| meta.podcast.is_some())
+            .map(|(path, _)| path)
+            .collect();
 
-        if path.is_dir() {
-            if let Some(name) = path.file_name() {
-                // If the selected folder is target_path (Not case sensitive) it is chosen as the main base folder
-                if name.to_string_lossy().eq_ignore_ascii_case(target_path) {
-                    return Some(path);
-                }
-            }
+        if self.selected_episode.as_ref().is_none_or(|s| !self.episodes.contains(s)) {
+            self.selected_episode = self.episodes.last().cloned();
         }
     }
-    None
-}
 
-/*
-Purpose: Creates a new project folder in a desired location with subfolders for dividing up your work flow.
-Args: base_dir (Path) - file location for desired folder
-      folder_name (String) - The new project's folder name in date_inputted name format
-Return: Error message if failed
-*/
-fn create_directory(base_dir: &Path, folder_name: &str, subfolders: &[&str]) -> io::Result<PathBuf> {
-    let main = base_dir.join(folder_name);
+    // Purpose: Writes the RSS item of the selected episode
+    fn make_rss_item(&mut self) {
+        let Some(episode) = self.selected_episode.clone() else {
+            self.status = "Select an episode first".to_string();
+            return;
+        };
 
-    if main.exists() {
-        return Err(io::Error::new(io::ErrorKind::AlreadyExists, "Folder exists"));
+        self.status = match podcast::write_rss_item(&episode, &self.config) {
+            Ok(path) => format!("RSS item written to {}", path.to_string_lossy()),
+            Err(e) => format!("Error: {}", e),
+        };
     }
 
-    // If there is no folder in the base_dir with folder_name, a new project foler is created
-    fs::create_dir(&main)?;
-
-    for sub in subfolders {
-        fs::create_dir(main.join(sub))?; // Creates subfolders
-    }
+    // Purpose: Draws the podcast window for the episodes in the base folder
+    fn podcast_window(&mut self, ctx: &egui::Context) {
+        let mut open = self.show_podcast;
+
+        egui::Window::new("Podcast").open(&mut open).show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                let selected = self.selected_episode.as_ref().map(|p| folder_label(p)).unwrap_or_default();
+                egui::ComboBox::from_label("Episode")
+                    .selected_text(selected)
+                    .show_ui(ui, |ui| {
+                        for episode in &self.episodes {
+                            ui.selectable_value(&mut self.selected_episode, Some(episode.clone()), folder_label(episode));
+                        }
+                    });
 
-    if main.join("Save").exists(){
-        // Creates a premiere save file
-        let prproj_template = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("Assets").join("template.prproj");
-        let project_name: Vec<&str> = folder_name.split("_").collect();
-        let prproj_name = format!("{}.prproj", project_name[1]);
-        let prproj = main.join("Save").join(prproj_name);
-        fs::copy(prproj_template, prproj)?;
+                if ui.button("Refresh").clicked() {
+                    self.refresh_episodes();
+                }
+            });
 
-        // Creates a photoshop save file
-        let psd_template = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("Assets").join("template.psd");
-        let psd_name = format!("{}.psd", project_name[1]);
-        let psd = main.join("Photoshop").join(psd_name);
-        fs::copy(psd_template, psd)?;
+            ui.label("Uses the first mp3, m4a or wav in the episode's Export folder and its show notes.");
+            if ui.add_enabled(self.selected_episode.is_some(), egui::Button::new("Make RSS Item")).clicked() {
+                self.make_rss_item();
+            }
+        });
 
-        // Creates a photoshop save file
-        let md_template = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("Assets").join("template.md");
-        let prefix = project_name[1].get(..3).unwrap_or(&project_name[1]);
-        let md_name = format!("{}_ideas.md", prefix);
-        let md = main.join(md_name);
-        fs::copy(md_template, md)?;
+        self.show_podcast = open;
     }
 
-    Ok(main)
-}
-
-#[derive(Default)]
-struct MyApp {
-    search_folder_name: String, // Name for base_path that will be searched for
-    folder_name: String, // Name for new project folder
-    status: String, // Text shown to explain what is currently happening
+    // Purpose: Reads the library assets, their tags and the projects using them
+    fn refresh_library(&mut self) {
+        let Some(base_dir) = &self.base_path else {
+            return;
+        };
 
-    base_path: Option<PathBuf>, // Path where new project folders are placed
-    project_path: PathBuf, // Path to new project folder created
-    project_type: ProjectType, // Indicates what type of project created
+        let index = match LibraryIndex::load(base_dir) {
+            Ok(index) => index,
+            Err(e) => {
+                self.status = format!("Error: {}", e);
+                return;
+            }
+        };
+        self.library_assets = library::usage_report(base_dir, &self.config)
+            .into_iter()
+            .map(|(asset, projects)| {
+                let tags = index.tags.get(&asset).map(|t| t.join(", ")).unwrap_or_default();
+                (asset, tags.clone(), tags, projects)
+            })
+            .collect();
+    }
 
-    scan_status: Arc<Mutex<ScanStatus>>, // The current state of the application
-    pending_create: bool, // Indicator for if the base_path 
-}
+    // Purpose: Writes the tags typed in the library window
+    fn save_library_tags(&mut self) {
+        let Some(base_dir) = &self.base_path else {
+            return;
+        };
 
-impl Default for ScanStatus {
-    fn default() -> Self {
-        ScanStatus::Idle
+        // Read again so tags of assets added since the window was filled are kept, only edited rows are changed
+        let mut index = match LibraryIndex::load(base_dir) {
+            Ok(index) => index,
+            Err(e) => {
+                self.status = format!("Error: {}", e);
+                return;
+            }
+        };
+        for (asset, tags, read, _) in &self.library_assets {
+            if tags != read {
+                index.set_tags(asset, tags);
+            }
+        }
+        self.status = match index.save(base_dir) {
+            Ok(()) => {
+                for (_, tags, read, _) in &mut self.library_assets {
+                    *read = tags.clone();
+                }
+                "Library tags saved".to_string()
+            }
+            Err(e) => format!("Error: {}", e),
+        };
     }
-}
 
-impl Default for ProjectType {
-    fn default() -> Self {
-        ProjectType::None
-    }
-}
+    // Purpose: Draws the asset library window for tagging assets and choosing what new projects get
+    fn library_window(&mut self, ctx: &egui::Context) {
+        let mut open = self.show_library;
 
-impl MyApp {
-    // Purpose: Scans for base folder directory
-    fn start_scan(&mut self) { 
-        self.status = format!("Searching for {} folder",self.search_folder_name);
+        egui::Window::new("Asset Library").open(&mut open).show(ctx, |ui| {
+            if let Some(base_dir) = &self.base_path {
+                ui.label(format!("Library folder: {}", library::library_dir(base_dir, &self.config).to_string_lossy()));
+            }
 
-        let scan_status = Arc::clone(&self.scan_status);
-        let target = self.search_folder_name.clone();
+            // Every asset with its tags and the projects it was placed into
+            egui::ScrollArea::vertical().id_source("library_assets").max_height(250.0).show(ui, |ui| {
+                egui::Grid::new("library_grid").num_columns(3).striped(true).show(ui, |ui| {
+                    for heading in ["Asset", "Tags", "Used by"] {
+                        ui.strong(heading);
+                    }
+                    ui.end_row();
+
+                    for (asset, tags, _, projects) in &mut self.library_assets {
+                        ui.label(asset.as_str());
+                        ui.add(egui::TextEdit::singleline(tags).hint_text("intro, logo").desired_width(150.0));
+                        let used = ui.label(format!("{} projects", projects.len()));
+                        if !projects.is_empty() {
+                            used.on_hover_text(projects.join("\n"));
+                        }
+                        ui.end_row();
+                    }
+                });
+            });
 
-        *scan_status.lock().unwrap() = ScanStatus::Scanning;
+            ui.horizontal(|ui| {
+                if ui.button("Refresh").clicked() {
+                    self.refresh_library();
+                }
+                if ui.button("Save Tags").clicked() {
+                    self.save_library_tags();
+                }
+            });
 
-        std::thread::spawn(move || {
-            let found = get_base_dir(Path::new("C:/"), &target);
-            
-            let mut status = scan_status.lock().unwrap();
-            *status = match found {
-                Some(path) => ScanStatus::Found(path),
-                None => ScanStatus::NotFound,
+            // Entries placing the assets of a tag into every new project of a type
+            ui.separator();
+            ui.label("New projects get:");
+            let mut remove = None;
+            for (i, entry) in self.config.library_entries.iter().enumerate() {
+                ui.horizontal(|ui| {
+                    ui.label(format!("{}: assets tagged '{}' in {}", entry.project_type.label(), entry.tag, entry.folder));
+                    if ui.small_button("Remove").clicked() {
+                        remove = Some(i);
+                    }
+                });
+            }
+            if let Some(i) = remove {
+                self.config.library_entries.remove(i);
+                self.save_config();
             }
+
+            ui.horizontal(|ui| {
+                egui::ComboBox::from_id_source("library_entry_type")
+                    .selected_text(self.new_library_entry.project_type.label())
+                    .show_ui(ui, |ui| {
+                        for project_type in ProjectType::ALL {
+                            ui.selectable_value(&mut self.new_library_entry.project_type, project_type, project_type.label());
+                        }
+                    });
+                ui.add(egui::TextEdit::singleline(&mut self.new_library_entry.tag).hint_text("tag").desired_width(80.0));
+                ui.add(egui::TextEdit::singleline(&mut self.new_library_entry.folder).hint_text("B-roll/Intro").desired_width(120.0));
+
+                let ready = !self.new_library_entry.tag.trim().is_empty();
+                if ui.add_enabled(ready, egui::Button::new("Add")).clicked() {
+                    let entry = std::mem::take(&mut self.new_library_entry);
+                    self.new_library_entry.project_type = entry.project_type;
+                    self.config.library_entries.push(LibraryEntry { tag: entry.tag.trim().to_lowercase(), folder: entry.folder.trim().to_string(), ..entry });
+                    self.save_config();
+                }
+            });
+            ui.small("The folder is inside the new project, empty places the assets in the project folder itself.");
         });
+
+        self.show_library = open;
     }
 
-    // Gets folder name and Project folder path
-    fn create_project(&mut self) {
-        if self.folder_name.trim().is_empty() {
-            self.status = "Folder name cannot be empty".to_string();
+    // Purpose: Reads the projects with licenses and checks the selected one's ledger
+    fn refresh_licenses(&mut self) {
+        let Some(base_dir) = &self.base_path else {
             return;
+        };
+
+        self.license_projects = metadata::list_projects(base_dir)
+            .into_iter()
+            .filter(|(_, meta)| !matches!(meta.project_type, ProjectType::School | ProjectType::Series))
+            .collect();
+        if self.selected_license_project.as_ref().is_none_or(|s| !self.license_projects.iter().any(|(p, _)| p == s)) {
+            self.selected_license_project = self.license_projects.last().map(|(p, _)| p.clone());
         }
 
-        if self.base_path.is_none() {
-            self.pending_create = true;
-            self.start_scan();
+        self.unlicensed_files.clear();
+        self.license_warnings.clear();
+        if let Some((project, meta)) = self.license_projects.iter().find(|(p, _)| Some(p) == self.selected_license_project.as_ref()) {
+            self.unlicensed_files = license::licensed_files(project, meta, &self.config.licensed_folders)
+                .into_iter()
+                .filter(|file| !meta.licenses.iter().any(|e| &e.file == file))
+                .collect();
+            self.license_warnings = license::warnings(project, meta, &self.config.licensed_folders);
+        }
+    }
+
+    // Purpose: Records the entry typed in the licenses window
+    fn add_license(&mut self) {
+        let Some(project) = self.selected_license_project.clone() else {
+            self.status = "Select a project first".to_string();
             return;
+        };
+
+        let entry = std::mem::take(&mut self.new_license);
+        let file = entry.file.clone();
+        match license::add_entry(&project, entry) {
+            Ok(()) => {
+                self.status = format!("License recorded for {}", file);
+                self.refresh_licenses();
+            }
+            Err(e) => self.status = format!("Error: {}", e),
         }
+    }
 
-        self.finish_create_project();
+    // Purpose: Draws the licenses window for a project's music and stock footage
+    fn licenses_window(&mut self, ctx: &egui::Context) {
+        let mut open = self.show_licenses;
+
+        egui::Window::new("Licenses").open(&mut open).show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                let selected = self.selected_license_project.as_ref().map(|p| folder_label(p)).unwrap_or_default();
+                let before = self.selected_license_project.clone();
+                egui::ComboBox::from_label("Project")
+                    .selected_text(selected)
+                    .show_ui(ui, |ui| {
+                        for (project, _) in &self.license_projects {
+                            ui.selectable_value(&mut self.selected_license_project, Some(project.clone()), folder_label(project));
+                        }
+                    });
+
+                if ui.button("Refresh").clicked() || before != self.selected_license_project {
+                    self.refresh_licenses();
+                }
+            });
+
+            let Some((project, meta)) = self.license_projects.iter().find(|(p, _)| Some(p) == self.selected_license_project.as_ref()) else {
+                return;
+            };
+            let project = project.clone();
+            let folders = license::checked_folders(meta, &self.config.licensed_folders).join(", ");
+            ui.label(format!("Checked for music and stock footage: {} (change in Settings)", folders));
+
+            // Ledger of the selected project
+            let mut remove = None;
+            egui::ScrollArea::vertical().id_source("licenses").max_height(150.0).show(ui, |ui| {
+                egui::Grid::new("licenses_grid").num_columns(5).striped(true).show(ui, |ui| {
+                    for heading in ["File", "Source", "License", "Attribution", ""] {
+                        ui.strong(heading);
+                    }
+                    ui.end_row();
+
+                    for entry in &meta.licenses {
+                        ui.label(&entry.file);
+                        ui.label(&entry.source);
+                        ui.label(entry.license.label());
+                        ui.label(&entry.attribution);
+                        if ui.small_button("Remove").clicked() {
+                            remove = Some(entry.file.clone());
+                        }
+                        ui.end_row();
+                    }
+                });
+            });
+
+            let credits = license::attribution_block(&meta.licenses);
+            if !credits.is_empty() {
+                ui.label("Credits for the description:");
+                ui.add(egui::TextEdit::multiline(&mut credits.as_str()).desired_rows(3));
+            }
+
+            // Form for a new entry, unlicensed files can be picked instead of typed
+            ui.separator();
+            egui::Grid::new("license_form").num_columns(2).show(ui, |ui| {
+                ui.label("File:");
+                ui.horizontal(|ui| {
+                    ui.add(egui::TextEdit::singleline(&mut self.new_license.file).hint_text("Music/track.mp3"));
+                    egui::ComboBox::from_id_source("unlicensed_files")
+                        .selected_text("Unlicensed")
+                        .show_ui(ui, |ui| {
+                            for file in &self.unlicensed_files {
+                                if ui.selectable_label(self.new_license.file == *file, file).clicked() {
+                                    self.new_license.file = file.clone();
+                                }
+                            }
+                        });
+                });
+                ui.end_row();
+
+                ui.label("Source:");
+                ui.add(egui::TextEdit::singleline(&mut self.new_license.source).hint_text("Artist or website"));
+                ui.end_row();
+
+                ui.label("License:");
+                egui::ComboBox::from_id_source("license_kind")
+                    .selected_text(self.new_license.license.label())
+                    .show_ui(ui, |ui| {
+                        for kind in LicenseKind::ALL {
+                            ui.selectable_value(&mut self.new_license.license, kind, kind.label());
+                        }
+                    });
+                ui.end_row();
+
+                ui.label("Attribution:");
+                ui.add(egui::TextEdit::singleline(&mut self.new_license.attribution).hint_text("Empty writes one from the source for CC BY"));
+                ui.end_row();
+            });
+
+            if ui.add_enabled(!self.new_license.file.trim().is_empty(), egui::Button::new("Add License")).clicked() {
+                self.add_license();
+            }
+
+            if let Some(file) = remove {
+                match license::remove_entry(&project, &file) {
+                    Ok(()) => self.refresh_licenses(),
+                    Err(e) => self.status = format!("Error: {}", e),
+                }
+            }
+
+            for warning in &self.license_warnings {
+                ui.colored_label(ui.visuals().warn_fg_color, warning);
+            }
+        });
+
+        self.show_licenses = open;
     }
 
-    // Purpose: Creates new project folder
-    fn finish_create_project(&mut self) {
+    // Purpose: Draws the school window for adding courses to existing semesters
+    fn school_window(&mut self, ctx: &egui::Context) {
+        let mut open = self.show_school;
+
+        egui::Window::new("School").open(&mut open).show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                let selected = self.selected_semester.as_ref().map(|p| folder_label(p)).unwrap_or_default();
+                egui::ComboBox::from_label("Semester")
+                    .selected_text(selected)
+                    .show_ui(ui, |ui| {
+                        for (semester, _) in &self.semesters {
+                            ui.selectable_value(&mut self.selected_semester, Some(semester.clone()), folder_label(semester));
+                        }
+                    });
 
-        let subfolders: &[&str] = match self.project_type {
-            ProjectType::Youtube => &["A-roll", "B-roll", "Save", "Photoshop"],
-            ProjectType::School => &["HW", "Slides", "Exam"],
-            ProjectType::None => &[],
-        };
+                if ui.button("Refresh").clicked() {
+                    self.refresh_semesters();
+                }
+            });
 
-        let base_dir = self.base_path.as_ref().unwrap().clone();
-        let date = Local::now().format("%Y-%m-%d");
+            // Courses already in the selected semester
+            let selected = self.semesters.iter().find(|(p, _)| Some(p) == self.selected_semester.as_ref());
+            let info = selected.and_then(|(_, meta)| meta.school.clone());
+            if let Some(info) = &info {
+                if let (Some(start), Some(end)) = (info.start, info.end) {
+                    ui.label(format!("{} to {}", start, end));
+                }
+                for course in &info.courses {
+                    ui.label(format!("• {}", course.folder_name()));
+                }
+            }
 
-        let final_name: String = if matches!(self.project_type, ProjectType::Youtube) {
-            format!("{}_{}", date, self.folder_name.trim())            
-        } else {
-            format!("{}", self.folder_name.trim())
-        };
+            ui.separator();
+            ui.horizontal(|ui| {
+                ui.add(egui::TextEdit::singleline(&mut self.new_course).hint_text("CS101 Intro to Programming"));
+                if ui.add_enabled(self.selected_semester.is_some(), egui::Button::new("Add Course")).clicked() {
+                    self.add_course();
+                }
+            });
 
-        match create_directory(&base_dir, &final_name, subfolders) {
-            Ok(created_path) =>{
-                self.status = "Folder created successfully".to_string();
-                self.project_path = created_path;
-                self.pending_create = false;
+            let Some(info) = info else {
+                return;
+            };
 
-                // let _ = Command::new(r"C:\Program Files\Adobe\Adobe Premiere Pro 2025.exe").spawn();
-            } 
+            // Assignments sorted by due date, overdue ones in red
+            ui.separator();
+            ui.label("Assignments:");
+            let today = Local::now().date_naive();
+            let mut bundle = None;
+            egui::ScrollArea::vertical().id_source("assignments").max_height(150.0).show(ui, |ui| {
+                for assignment in info.assignments_by_due() {
+                    ui.horizontal(|ui| {
+                        let text = format!("{}  {}  {}", assignment.due, assignment.course, assignment.title);
+                        match assignment.submitted {
+                            Some(time) => {
+                                ui.label(format!("{} (submitted {})", text, time.format("%Y-%m-%d %H:%M")));
+                            }
+                            None if assignment.due < today => {
+                                ui.colored_label(ui.visuals().error_fg_color, format!("{} (overdue)", text));
+                            }
+                            None => {
+                                ui.label(text);
+                            }
+                        }
+
+                        if ui.small_button("Bundle submission").clicked() {
+                            bundle = Some(assignment.folder.clone());
+                        }
+                    });
+                }
+            });
+            if let Some(folder) = bundle {
+                self.bundle_submission(&folder);
+            }
+
+            ui.horizontal(|ui| {
+                egui::ComboBox::from_id_source("assignment_course")
+                    .selected_text(self.assignment_course.clone())
+                    .show_ui(ui, |ui| {
+                        for course in &info.courses {
+                            ui.selectable_value(&mut self.assignment_course, course.code.clone(), &course.code);
+                        }
+                    });
+                ui.add(egui::TextEdit::singleline(&mut self.assignment_title).hint_text("Assignment title").desired_width(140.0));
+                ui.add(egui_extras::DatePickerButton::new(self.assignment_due.get_or_insert(today)).id_source("assignment_due"));
+            });
+            ui.horizontal(|ui| {
+                ui.label("HW folder pattern:");
+                ui.add(egui::TextEdit::singleline(&mut self.assignment_pattern).hint_text(&info.assignment_pattern).desired_width(140.0));
+                if ui.button("Add Assignment").clicked() {
+                    self.add_assignment();
+                }
+                if ui.button("Export .ics").clicked() {
+                    self.export_assignments();
+                }
+            });
+            ui.small("Pattern fields: {title} {course} {due} {n}");
+
+            ui.horizontal(|ui| {
+                ui.label(format!("Submission zip name for {}:", self.assignment_course));
+                ui.add(egui::TextEdit::singleline(&mut self.course_zip_pattern).hint_text(&self.config.submission_pattern).desired_width(160.0));
+                if ui.add_enabled(!self.assignment_course.is_empty(), egui::Button::new("Set")).clicked() {
+                    self.set_course_zip_pattern();
+                }
+            });
+        });
+
+        self.show_school = open;
+    }
+
+    // Purpose: Forgets the base folder and current project, writing unsaved ideas edits first
+    fn reset_project_folder(&mut self) {
+        if let Err(e) = self.save_open_ideas() {
+            self.status = format!("Error: ideas not saved, project folder not reset ({})", e);
+            return;
+        }
+
+        self.project_path = PathBuf::new();
+        self.project_files.clear();
+        self.last_created = None;
+        self.close_ideas();
+        self.folder_name.clear();
+        self.base_path = None;
+        self.status = "Project folder reset".to_string();
+    }
+
+    // Purpose: Moves the last created project to the trash if it has not been modified
+    fn undo_create_project(&mut self) {
+        let (Some(base_dir), Some(project)) = (self.base_path.clone(), self.last_created.clone()) else {
+            self.status = "Nothing to undo".to_string();
+            return;
+        };
+
+        match undo::undo_create(&base_dir, &project) {
+            Ok(trashed) => {
+                self.status = format!("Project moved to trash: {}", trashed.to_string_lossy());
+                self.project_path = PathBuf::new();
+                self.project_files.clear();
+                self.last_created = None;
+                self.close_ideas();
+            }
             Err(e) => {
-                self.status = format!("Error: {}", e);
-                self.pending_create = false;
+                self.status = format!("Undo refused: {}", e);
             }
         }
     }
@@ -197,16 +2338,23 @@ impl eframe::App for MyApp {
                 self.start_scan();
             }
         } else {
-            if ctx.input(|i| i.key_pressed(egui::Key::Enter)) {
+            let typing_multiline = ctx.memory(|m| MULTILINE_TEXT_IDS.iter().any(|id| m.has_focus(egui::Id::new(id))));
+            if !typing_multiline && ctx.input(|i| i.key_pressed(egui::Key::Enter)) {
                 self.create_project();
             }
         }
 
-        // Closes program if esc key is pressed
+        // Closes program if esc key is pressed, writing unsaved ideas edits first
         if ctx.input(|i| i.key_pressed(egui::Key::Escape)) {
             ctx.send_viewport_cmd(egui::ViewportCommand::Close);
         }
 
+        // Escape, Exit and the window's close button all end up here
+        if ctx.input(|i| i.viewport().close_requested()) && let Err(e) = self.save_ideas_before_close() {
+            self.status = format!("Error: ideas not saved, close again to discard them ({})", e);
+            ctx.send_viewport_cmd(egui::ViewportCommand::CancelClose);
+        }
+
         // 
         let scan_action = {
             let scan_status = self.scan_status.lock().unwrap();
@@ -224,6 +2372,8 @@ impl eframe::App for MyApp {
                     self.base_path = Some(path);
                     self.status = "Base folder found".to_string();
                     *self.scan_status.lock().unwrap() = ScanStatus::Idle;
+                    self.load_config();
+                    self.refresh_video_projects();
 
                     if self.pending_create {
                         self.finish_create_project();
@@ -239,6 +2389,55 @@ impl eframe::App for MyApp {
             }
         }
 
+        // Menu bar for the extra tools, only usable once the base folder is known
+        if self.base_path.is_some() {
+            egui::TopBottomPanel::top("menu").show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    if ui.toggle_value(&mut self.show_projects, "Projects").clicked() {
+                        self.refresh_projects();
+                    }
+                    ui.toggle_value(&mut self.show_batch, "Batch create");
+                    if ui.toggle_value(&mut self.show_school, "School").clicked() {
+                        self.refresh_semesters();
+                    }
+                    if ui.toggle_value(&mut self.show_video, "Video").clicked() {
+                        self.refresh_video_projects();
+                    }
+                    if ui.toggle_value(&mut self.show_podcast, "Podcast").clicked() {
+                        self.refresh_episodes();
+                    }
+                    if ui.toggle_value(&mut self.show_library, "Library").clicked() {
+                        self.refresh_library();
+                    }
+                    if ui.toggle_value(&mut self.show_licenses, "Licenses").clicked() {
+                        self.refresh_licenses();
+                    }
+                    if ui.toggle_value(&mut self.show_inbox, "Inbox").clicked() {
+                        self.refresh_inbox();
+                    }
+                    if ui.toggle_value(&mut self.show_calendar, "Calendar").clicked() {
+                        self.refresh_calendar();
+                    }
+                    ui.toggle_value(&mut self.show_search, "Search");
+                    ui.toggle_value(&mut self.show_ideas, "Ideas");
+                    ui.toggle_value(&mut self.show_settings, "Settings");
+                });
+            });
+
+            self.projects_window(ctx);
+            self.batch_window(ctx);
+            self.school_window(ctx);
+            self.video_window(ctx);
+            self.podcast_window(ctx);
+            self.library_window(ctx);
+            self.licenses_window(ctx);
+            self.inbox_window(ctx);
+            self.search_window(ctx);
+            self.calendar_window(ctx);
+            self.settings_window(ctx);
+            self.ideas_panel(ctx);
+        }
+
         // Main GUI interface
         egui::CentralPanel::default().show(ctx, |ui| {
             // Heading showing title of software
@@ -272,12 +2471,87 @@ impl eframe::App for MyApp {
                     ui.add_space(10.0);
                 });
 
+                // Date used for the folder name, today unless another date is picked
+                ui.horizontal(|ui| {
+                    let mut pick_date = self.project_date.is_some();
+                    ui.checkbox(&mut pick_date, "Use another date");
+
+                    match (pick_date, &mut self.project_date) {
+                        (true, Some(date)) => {
+                            ui.add(egui_extras::DatePickerButton::new(date).id_source("project_date"));
+                        }
+                        (true, None) => self.project_date = Some(Local::now().date_naive()),
+                        (false, _) => self.project_date = None,
+                    }
+
+                    ui.radio_value(&mut self.date_kind, DateKind::Created, "Created date");
+                    ui.radio_value(&mut self.date_kind, DateKind::Publish, "Publish date");
+                });
+
+                // Series a new video is added to as its next episode
+                if matches!(self.project_type, ProjectType::Youtube | ProjectType::Shorts) {
+                    ui.horizontal(|ui| {
+                        let selected = self.selected_series.as_ref().map(|p| folder_label(p)).unwrap_or("None".to_string());
+                        egui::ComboBox::from_label("Series")
+                            .selected_text(selected)
+                            .show_ui(ui, |ui| {
+                                ui.selectable_value(&mut self.selected_series, None, "None");
+                                for series_dir in &self.series_projects {
+                                    ui.selectable_value(&mut self.selected_series, Some(series_dir.clone()), folder_label(series_dir));
+                                }
+                            });
+                        if let Some(series_dir) = &self.selected_series {
+                            ui.label(format!("Next episode: E{:02}", series::next_episode(series_dir)));
+                        }
+                    });
+                }
+
+                // Long-form project a new Shorts project is cut from
+                if self.project_type == ProjectType::Shorts {
+                    let selected = self.shorts_parent.clone().unwrap_or("None".to_string());
+                    egui::ComboBox::from_label("Cut from")
+                        .selected_text(selected)
+                        .show_ui(ui, |ui| {
+                            ui.selectable_value(&mut self.shorts_parent, None, "None");
+                            for (_, meta) in self.video_projects.iter().filter(|(_, m)| m.project_type == ProjectType::Youtube) {
+                                ui.selectable_value(&mut self.shorts_parent, Some(meta.relative_path()), meta.relative_path());
+                            }
+                        });
+                }
+
+                // Hosts and guests of a new podcast episode
+                if self.project_type == ProjectType::Podcast {
+                    ui.horizontal(|ui| {
+                        let next = self.base_path.as_ref().map(|b| podcast::next_episode(b)).unwrap_or(1);
+                        ui.label(format!("Episode {}, speakers:", next));
+                        ui.add(egui::TextEdit::singleline(&mut self.speakers_text).hint_text("Alex, Sam (guest)"));
+                    });
+                }
+
+                // Semester dates and first courses when creating a School project
+                if self.project_type == ProjectType::School {
+                    let today = Local::now().date_naive();
+                    ui.horizontal(|ui| {
+                        ui.label("Semester:");
+                        ui.add(egui_extras::DatePickerButton::new(self.semester_start.get_or_insert(today)).id_source("semester_start"));
+                        ui.label("to");
+                        ui.add(egui_extras::DatePickerButton::new(self.semester_end.get_or_insert(today + chrono::Duration::weeks(15))).id_source("semester_end"));
+                    });
+                    ui.add(
+                        egui::TextEdit::multiline(&mut self.courses_text)
+                            .id(egui::Id::new("courses_text"))
+                            .hint_text("Courses, one per line (CS101 Intro to Programming)")
+                            .desired_rows(3),
+                    );
+                }
+
                 let create_enabled = self.project_type != ProjectType::None;
 
                 ui.with_layout(egui::Layout::right_to_left(egui::Align::TOP), |ui|{
                     ui.horizontal_wrapped(|ui| {
-                        ui.radio_value(&mut self.project_type, ProjectType::Youtube, "Youtube");
-                        ui.radio_value(&mut self.project_type, ProjectType::School, "School");
+                        for project_type in ProjectType::ALL {
+                            ui.radio_value(&mut self.project_type, project_type, project_type.label());
+                        }
 
                         ui.end_row();
 
@@ -286,11 +2560,12 @@ impl eframe::App for MyApp {
                             self.create_project();
                         }
 
+                        if ui.add_enabled(self.last_created.is_some(), egui::Button::new("Undo Create")).clicked() {
+                            self.undo_create_project();
+                        }
+
                         if ui.button("Reset Project Folder").clicked() {
-                            self.project_path = PathBuf::new();
-                            self.folder_name.clear();
-                            self.base_path = None;
-                            self.status = "Project folder reset".to_string();
+                            self.reset_project_folder();
                         }                      
                     });          
                 });
@@ -325,7 +2600,7 @@ impl eframe::App for MyApp {
             ui.vertical_centered(|ui|{
                 match &self.base_path {
                     Some(path) => {
-                        ui.label(format!("Base path: {}", path.to_string_lossy().to_string()));
+                        ui.label(format!("Base path: {}", path.to_string_lossy()));
                         
                     } None => {
                         ui.label("No base path selected");
@@ -335,8 +2610,41 @@ impl eframe::App for MyApp {
 
             if !self.project_path.to_string_lossy().to_string().is_empty() {
                ui.vertical_centered(|ui|{
-                    ui.label(format!("Project path: {}", self.project_path.to_string_lossy().to_string()));
-                    ctx.copy_text(self.project_path.to_string_lossy().to_string());
+                    ui.horizontal(|ui| {
+                        ui.label(format!("Project path: {}", self.project_path.to_string_lossy()));
+                        if ui.small_button("Copy path").on_hover_text(self.config.copy_format.label()).clicked() {
+                            self.copy_pending = true;
+                            self.status = "Project path copied".to_string();
+                        }
+                    });
+
+                    // Copied once when asked for, so the clipboard is not overwritten every frame
+                    if self.copy_pending {
+                        ctx.copy_text(self.config.copy_format.format(&self.project_path));
+                        self.copy_pending = false;
+                    }
+
+                    // Buttons opening the new project and the files made in it
+                    let mut open_path = None;
+                    ui.horizontal(|ui| {
+                        if ui.button("Open Folder").clicked() {
+                            open_path = Some(self.project_path.clone());
+                        }
+                        if self.ideas.is_some() {
+                            ui.toggle_value(&mut self.show_ideas, "Plan Here");
+                        }
+                        for (label, path) in &self.project_files {
+                            if ui.button(format!("Open {}", label)).clicked() {
+                                open_path = Some(path.clone());
+                            }
+                        }
+                    });
+                    if let Some(path) = open_path {
+                        self.open_path(&path);
+                    }
+                    for line in self.hook_report.lock().unwrap().iter() {
+                        ui.label(line);
+                    }
                });
             }
         });
@@ -345,11 +2653,51 @@ impl eframe::App for MyApp {
     }
 }
 
+// Purpose: Gets the folder name of a path for showing in lists
+fn folder_label(path: &Path) -> String {
+    path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default()
+}
+
+// Purpose: Gets the colour a status is drawn in on the content calendar
+fn status_color(status: ProjectStatus) -> egui::Color32 {
+    match status {
+        ProjectStatus::Planned => egui::Color32::GRAY,
+        ProjectStatus::Filming => egui::Color32::from_rgb(230, 160, 40),
+        ProjectStatus::Editing => egui::Color32::from_rgb(200, 120, 220),
+        ProjectStatus::Scheduled => egui::Color32::from_rgb(80, 150, 240),
+        ProjectStatus::Published => egui::Color32::from_rgb(90, 190, 90),
+    }
+}
+
+/*
+Purpose: Draws a project on the content calendar that can be dragged to another day
+Args: ui (Ui) - calendar cell or unscheduled list
+      project (Path) - project folder, used as the drag payload
+      meta (ProjectMeta) - project metadata
+      selected (bool) - whether the project is the one being edited
+Return: True if the project was clicked
+*/
+fn calendar_entry(ui: &mut egui::Ui, project: &Path, meta: &ProjectMeta, selected: bool) -> bool {
+    let id = egui::Id::new(("calendar_entry", project));
+    let text = egui::RichText::new(&meta.name).small().color(status_color(meta.status));
+
+    ui.dnd_drag_source(id, project.to_path_buf(), |ui| ui.selectable_label(selected, text))
+        .inner
+        .on_hover_text(format!("{} project, {}", meta.project_type.label(), meta.status.label()))
+        .clicked()
+}
+
 fn main() -> eframe::Result<()> {
+    // Any arguments run the command line version instead of the GUI
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if !args.is_empty() {
+        std::process::exit(cli::run(&args));
+    }
+
     // let options = eframe::NativeOptions::default();
     let options = eframe::NativeOptions {
         viewport: egui::ViewportBuilder::default()
-            .with_inner_size([500.0, 250.0])
+            .with_inner_size([640.0, 420.0])
             .with_min_inner_size([500.0, 250.0])
             .with_transparent(true), // To have rounded corners we need transparency
 
@@ -0,0 +1,404 @@
+use std::{fs, io}; // OS crate
+use std::io::{Read, Seek, SeekFrom}; // Jumps between atoms without reading the media data
+use std::path::{Path, PathBuf}; // File path crate
+use chrono::{DateTime, Duration, Local, NaiveDate, TimeZone, Utc}; // Recording times stored in the atoms
+
+use crate::mlt::VideoProfile;
+
+pub const VIDEO_EXTENSIONS: [&str; 3] = ["mp4", "mov", "m4v"]; // Files read as clips (Not case sensitive)
+pub const FOOTAGE_FOLDERS: [&str; 2] = ["A-roll", "B-roll"]; // Project folders clips are read from
+
+// What was read from one clip's container
+#[derive(Debug, Clone)]
+pub struct Clip {
+    pub path: PathBuf, // Clip file
+    pub recorded: Option<DateTime<Local>>, // Creation time stored by the camera
+    pub duration: Option<f64>, // Length in seconds
+    pub has_audio: bool, // Has a sound track
+    pub modified: Option<DateTime<Local>>, // File modified time, used for ordering when there is no creation time
+    pub width: Option<u32>, // Display width of the video track in pixels
+    pub height: Option<u32>, // Display height of the video track in pixels
+    pub fps: Option<f64>, // Average frames per second of the video track
+    pub codec: Option<String>, // Four letter code of the video format such as "avc1" or "hvc1"
+}
+
+impl Clip {
+    // Purpose: Gets the clip's file name
+    pub fn name(&self) -> String {
+        self.path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default()
+    }
+
+    // Purpose: Gets the time used to put clips in recording order
+    pub fn order_time(&self) -> Option<DateTime<Local>> {
+        self.recorded.or(self.modified)
+    }
+
+    /*
+    Purpose: Compares the clip with a project's sequence settings
+    Args: profile (VideoProfile) - project resolution and frame rate
+    Return: One line per setting that does not match (empty if the clip fits)
+    */
+    pub fn mismatches(&self, profile: &VideoProfile) -> Vec<String> {
+        let mut found = Vec::new();
+        if let (Some(width), Some(height)) = (self.width, self.height)
+            && (width, height) != (profile.width, profile.height)
+        {
+            found.push(format!("{}x{}, project is {}x{}", width, height, profile.width, profile.height));
+        }
+        if let Some(fps) = self.fps
+            && (fps - profile.fps()).abs() > 0.01
+        {
+            found.push(format!("{:.2} fps, project is {} fps", fps, profile.fps_label()));
+        }
+        found
+    }
+}
+
+// Purpose: Reads a big-endian number of up to 8 bytes
+fn read_uint(file: &mut fs::File, bytes: usize) -> io::Result<u64> {
+    let mut buffer = [0u8; 8];
+    file.read_exact(&mut buffer[8 - bytes..])?;
+    Ok(u64::from_be_bytes(buffer))
+}
+
+/*
+Purpose: Lists the atoms (boxes) between two file offsets
+Args: file (File) - open MP4/MOV file
+      start (u64) - offset of the first atom
+      end (u64) - offset the atoms stop at
+Return: (type, offset of the contents, offset after the atom) for each atom
+*/
+fn atoms(file: &mut fs::File, start: u64, end: u64) -> io::Result<Vec<([u8; 4], u64, u64)>> {
+    let mut found = Vec::new();
+    let mut offset = start;
+
+    while end.saturating_sub(offset) >= 8 {
+        file.seek(SeekFrom::Start(offset))?;
+        let mut size = read_uint(file, 4)?;
+        let mut kind = [0u8; 4];
+        file.read_exact(&mut kind)?;
+        let mut header = 8;
+
+        // Size 1 means a 64 bit size follows, 0 means the atom runs to the end
+        if size == 1 {
+            size = read_uint(file, 8)?;
+            header = 16;
+        } else if size == 0 {
+            size = end - offset;
+        }
+        // A size running past the end (or past what a u64 holds) is a truncated or broken file, the atoms before it are kept
+        let Some(atom_end) = offset.checked_add(size).filter(|&atom_end| atom_end <= end) else {
+            break;
+        };
+        if size < header {
+            break;
+        }
+
+        found.push((kind, offset + header, atom_end));
+        offset = atom_end;
+    }
+    Ok(found)
+}
+
+// Purpose: Finds the first child atom of a type
+fn child(file: &mut fs::File, start: u64, end: u64, kind: &[u8; 4]) -> io::Result<Option<(u64, u64)>> {
+    Ok(atoms(file, start, end)?.into_iter().find(|(k, _, _)| k == kind).map(|(_, s, e)| (s, e)))
+}
+
+// Purpose: Converts seconds since 1904-01-01 (the MP4 epoch) into a local time
+fn mp4_time(seconds: u64) -> Option<DateTime<Local>> {
+    if seconds == 0 {
+        return None; // Many cameras leave the time unset
+    }
+    let epoch = NaiveDate::from_ymd_opt(1904, 1, 1)?.and_hms_opt(0, 0, 0)?;
+    let time = epoch.checked_add_signed(Duration::seconds(i64::try_from(seconds).ok()?))?;
+    Some(Utc.from_utc_datetime(&time).with_timezone(&Local))
+}
+
+/*
+Purpose: Reads the recording time, length and tracks of an MP4/MOV clip
+Args: path (Path) - clip file
+Return: What was found, fields the file does not have are left empty
+*/
+pub fn read_clip(path: &Path) -> io::Result<Clip> {
+    let mut file = fs::File::open(path)?;
+    let file_len = file.metadata()?.len();
+    let mut clip = Clip {
+        path: path.to_path_buf(),
+        recorded: None,
+        duration: None,
+        has_audio: false,
+        modified: file.metadata()?.modified().ok().map(DateTime::<Local>::from),
+        width: None,
+        height: None,
+        fps: None,
+        codec: None,
+    };
+
+    let Some((moov, moov_end)) = child(&mut file, 0, file_len, b"moov")? else {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "Not an MP4/MOV file (no moov atom)"));
+    };
+
+    // Movie header: creation time, time scale and duration, with 64 bit fields in version 1
+    if let Some((mvhd, _)) = child(&mut file, moov, moov_end, b"mvhd")? {
+        file.seek(SeekFrom::Start(mvhd))?;
+        let version = read_uint(&mut file, 1)?;
+        read_uint(&mut file, 3)?;
+        let width = if version == 1 { 8 } else { 4 };
+        let created = read_uint(&mut file, width)?;
+        read_uint(&mut file, width)?;
+        let timescale = read_uint(&mut file, 4)?;
+        let duration = read_uint(&mut file, width)?;
+
+        clip.recorded = mp4_time(created);
+        clip.duration = (timescale > 0).then(|| duration as f64 / timescale as f64);
+    }
+
+    // The handler of each track's media says whether it is sound or video
+    for (kind, trak, trak_end) in atoms(&mut file, moov, moov_end)? {
+        if &kind != b"trak" {
+            continue;
+        }
+        let Some((mdia, mdia_end)) = child(&mut file, trak, trak_end, b"mdia")? else {
+            continue;
+        };
+        let Some((hdlr, _)) = child(&mut file, mdia, mdia_end, b"hdlr")? else {
+            continue;
+        };
+        file.seek(SeekFrom::Start(hdlr + 8))?;
+        let mut handler = [0u8; 4];
+        file.read_exact(&mut handler)?;
+
+        match &handler {
+            b"soun" => clip.has_audio = true,
+            b"vide" if clip.codec.is_none() => read_video_track(&mut file, &mut clip, (trak, trak_end), (mdia, mdia_end))?,
+            _ => {}
+        }
+    }
+
+    Ok(clip)
+}
+
+/*
+Purpose: Reads the size, frame rate and codec of a video track into a clip
+Args: file (File) - open MP4/MOV file
+      clip (Clip) - clip the values are stored in
+      trak (u64 pair) - contents of the track atom
+      mdia (u64 pair) - contents of the track's media atom
+Return: Error message if the file could not be read
+*/
+fn read_video_track(file: &mut fs::File, clip: &mut Clip, trak: (u64, u64), mdia: (u64, u64)) -> io::Result<()> {
+    // Track header: a rotation matrix followed by the size as 16.16 fixed point numbers
+    if let Some((tkhd, _)) = child(file, trak.0, trak.1, b"tkhd")? {
+        file.seek(SeekFrom::Start(tkhd))?;
+        let version = read_uint(file, 1)?;
+        file.seek(SeekFrom::Start(tkhd + if version == 1 { 52 } else { 40 }))?;
+        read_uint(file, 4)?;
+        let rotated = read_uint(file, 4)? != 0; // Phones record portrait video as turned landscape frames
+        file.seek(SeekFrom::Current(28))?;
+        let width = (read_uint(file, 4)? >> 16) as u32;
+        let height = (read_uint(file, 4)? >> 16) as u32;
+        if width > 0 && height > 0 {
+            let (width, height) = if rotated { (height, width) } else { (width, height) };
+            clip.width = Some(width);
+            clip.height = Some(height);
+        }
+    }
+
+    // Media header: time scale and length of the track
+    let mut media_time = None;
+    if let Some((mdhd, _)) = child(file, mdia.0, mdia.1, b"mdhd")? {
+        file.seek(SeekFrom::Start(mdhd))?;
+        let version = read_uint(file, 1)?;
+        read_uint(file, 3)?;
+        let width = if version == 1 { 8 } else { 4 };
+        read_uint(file, width * 2)?;
+        let timescale = read_uint(file, 4)?;
+        let duration = read_uint(file, width)?;
+        media_time = (timescale > 0 && duration > 0).then_some((timescale, duration));
+    }
+
+    let Some((minf, minf_end)) = child(file, mdia.0, mdia.1, b"minf")? else {
+        return Ok(());
+    };
+    let Some((stbl, stbl_end)) = child(file, minf, minf_end, b"stbl")? else {
+        return Ok(());
+    };
+
+    // Sample description: the first entry's type is the codec, followed by the coded size
+    if let Some((stsd, _)) = child(file, stbl, stbl_end, b"stsd")? {
+        file.seek(SeekFrom::Start(stsd + 12))?;
+        let mut codec = [0u8; 4];
+        file.read_exact(&mut codec)?;
+        clip.codec = Some(String::from_utf8_lossy(&codec).trim().to_string());
+
+        if clip.width.is_none() {
+            file.seek(SeekFrom::Start(stsd + 16 + 24))?;
+            clip.width = Some(read_uint(file, 2)? as u32);
+            clip.height = Some(read_uint(file, 2)? as u32);
+        }
+    }
+
+    // Time to sample table: number of frames over the track length gives the average frame rate
+    if let (Some((stts, _)), Some((timescale, duration))) = (child(file, stbl, stbl_end, b"stts")?, media_time) {
+        file.seek(SeekFrom::Start(stts + 4))?;
+        let entries = read_uint(file, 4)?;
+        let mut samples = 0;
+        for _ in 0..entries.min(100_000) {
+            samples += read_uint(file, 4)?;
+            read_uint(file, 4)?;
+        }
+        if samples > 0 {
+            clip.fps = Some(samples as f64 * timescale as f64 / duration as f64);
+        }
+    }
+
+    Ok(())
+}
+
+/*
+Purpose: Reads every MP4/MOV clip directly inside a folder
+Args: folder (Path) - footage folder such as A-roll
+Return: Clips in recording order, and "file: error" lines for files that could not be read
+*/
+pub fn list_clips(folder: &Path) -> (Vec<Clip>, Vec<String>) {
+    let Ok(entries) = fs::read_dir(folder) else {
+        return (Vec::new(), Vec::new());
+    };
+
+    let mut clips = Vec::new();
+    let mut errors = Vec::new();
+    for path in entries.filter_map(|e| e.ok().map(|e| e.path())) {
+        let is_video = path
+            .extension()
+            .is_some_and(|ext| VIDEO_EXTENSIONS.iter().any(|v| ext.to_string_lossy().eq_ignore_ascii_case(v)));
+        if !path.is_file() || !is_video {
+            continue;
+        }
+
+        match read_clip(&path) {
+            Ok(clip) => clips.push(clip),
+            Err(e) => errors.push(format!("{}: {}", path.file_name().unwrap_or_default().to_string_lossy(), e)),
+        }
+    }
+
+    clips.sort_by(|a, b| a.order_time().cmp(&b.order_time()).then(a.path.cmp(&b.path)));
+    (clips, errors)
+}
+
+/*
+Purpose: Reads the clips in every footage folder of a project
+Args: project (Path) - project folder
+Return: (footage folder, clip) pairs in folder then recording order, and lines for files that could not be read
+*/
+pub fn project_clips(project: &Path) -> (Vec<(&'static str, Clip)>, Vec<String>) {
+    let mut clips = Vec::new();
+    let mut errors = Vec::new();
+    for folder in FOOTAGE_FOLDERS {
+        let (found, failed) = list_clips(&project.join(folder));
+        clips.extend(found.into_iter().map(|clip| (folder, clip)));
+        errors.extend(failed.into_iter().map(|e| format!("{}/{}", folder, e)));
+    }
+    (clips, errors)
+}
+
+// Purpose: Formats a length in seconds as m:ss
+pub fn format_duration(seconds: f64) -> String {
+    let total = seconds.round() as u64;
+    format!("{}:{:02}", total / 60, total % 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Purpose: Writes bytes into a file in the temp folder for a test to open
+    fn temp_file(name: &str, bytes: &[u8]) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("y_template_media_{}_{}", std::process::id(), name));
+        fs::write(&path, bytes).unwrap();
+        path
+    }
+
+    // Purpose: Builds an atom with a 32 bit size
+    fn atom(kind: &[u8; 4], contents: &[u8]) -> Vec<u8> {
+        let mut bytes = ((contents.len() + 8) as u32).to_be_bytes().to_vec();
+        bytes.extend_from_slice(kind);
+        bytes.extend_from_slice(contents);
+        bytes
+    }
+
+    // Purpose: Lists the atoms of a whole file
+    fn file_atoms(name: &str, bytes: &[u8]) -> Vec<([u8; 4], u64, u64)> {
+        let path = temp_file(name, bytes);
+        let found = atoms(&mut fs::File::open(&path).unwrap(), 0, bytes.len() as u64).unwrap();
+        fs::remove_file(path).unwrap();
+        found
+    }
+
+    #[test]
+    fn lists_atoms_in_order() {
+        let bytes = [atom(b"ftyp", b"isom\0\0\0\0"), atom(b"free", b"")].concat();
+        assert_eq!(file_atoms("order", &bytes), vec![(*b"ftyp", 8, 16), (*b"free", 24, 24)]);
+    }
+
+    #[test]
+    fn reads_64_bit_and_open_ended_sizes() {
+        let mut bytes = vec![0, 0, 0, 1];
+        bytes.extend_from_slice(b"mdat");
+        bytes.extend_from_slice(&20u64.to_be_bytes());
+        bytes.extend_from_slice(&[0; 4]);
+        bytes.extend_from_slice(&0u32.to_be_bytes()); // Runs to the end of the file
+        bytes.extend_from_slice(b"free");
+        bytes.extend_from_slice(&[0; 4]);
+        assert_eq!(file_atoms("sizes", &bytes), vec![(*b"mdat", 16, 20), (*b"free", 28, 32)]);
+    }
+
+    #[test]
+    fn stops_at_a_64_bit_size_that_overflows() {
+        let mut bytes = atom(b"ftyp", b"isom");
+        bytes.extend_from_slice(&[0, 0, 0, 1]);
+        bytes.extend_from_slice(b"mdat");
+        bytes.extend_from_slice(&0xFFFF_FFFF_FFFF_FFF0u64.to_be_bytes());
+        assert_eq!(file_atoms("overflow", &bytes), vec![(*b"ftyp", 8, 12)]);
+    }
+
+    #[test]
+    fn stops_at_a_truncated_or_too_small_atom() {
+        let mut truncated = atom(b"ftyp", b"isom");
+        truncated.extend_from_slice(&100u32.to_be_bytes());
+        truncated.extend_from_slice(b"moov");
+        assert_eq!(file_atoms("truncated", &truncated), vec![(*b"ftyp", 8, 12)]);
+
+        let mut too_small = 4u32.to_be_bytes().to_vec();
+        too_small.extend_from_slice(b"free");
+        assert!(file_atoms("too_small", &too_small).is_empty());
+    }
+
+    #[test]
+    fn rejects_a_file_without_a_movie() {
+        let path = temp_file("no_moov.mp4", &atom(b"ftyp", b"isom"));
+        let error = read_clip(&path).unwrap_err();
+        fs::remove_file(path).unwrap();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn compares_ntsc_clips_with_the_fractional_rate() {
+        let clip = Clip {
+            path: PathBuf::from("A-roll/phone.mov"),
+            recorded: None,
+            duration: Some(10.0),
+            has_audio: true,
+            modified: None,
+            width: Some(1920),
+            height: Some(1080),
+            fps: Some(30000.0 / 1001.0),
+            codec: None,
+        };
+        let ntsc = VideoProfile { width: 1920, height: 1080, frame_rate_num: 30000, frame_rate_den: 1001 };
+        let whole = VideoProfile { frame_rate_num: 30, frame_rate_den: 1, ..ntsc };
+
+        assert!(clip.mismatches(&ntsc).is_empty());
+        assert_eq!(clip.mismatches(&whole), vec!["29.97 fps, project is 30 fps".to_string()]);
+    }
+}
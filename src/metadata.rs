@@ -0,0 +1,158 @@
+use std::{fs, io}; // OS crate
+use std::collections::BTreeMap; // Sorted key/value storage
+use std::path::{Path, PathBuf}; // File path crate
+use chrono::{DateTime, Local, NaiveDate}; // Time stamps stored with each project
+use serde::{Deserialize, Serialize}; // Reads and writes the metadata as JSON
+
+use crate::{DateKind, ProjectType};
+use crate::calendar::ProjectStatus;
+use crate::library::LinkedAsset;
+use crate::license::LicenseEntry;
+use crate::podcast::PodcastInfo;
+use crate::series::SeriesEpisode;
+use crate::school::SchoolInfo;
+use crate::undo::FileRecord;
+
+pub const META_FILE: &str = ".project.json"; // Hidden file inside every project folder holding its metadata
+pub const APP_DIR: &str = ".y_template"; // Hidden folder in the base path used for the tool's own data
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectMeta {
+    pub name: String, // Final folder name of the project
+    pub project_type: ProjectType, // Type chosen when the project was created
+    pub created: DateTime<Local>, // When the project folder was created
+
+    #[serde(default)]
+    pub date: NaiveDate, // Date used for the folder name prefix
+
+    #[serde(default)]
+    pub date_kind: DateKind, // Whether date is the created or the publish date
+
+    #[serde(default)]
+    pub publish_date: Option<NaiveDate>, // Planned publish date, if known
+
+    #[serde(default)]
+    pub status: ProjectStatus, // How far along the project is (shown on the content calendar)
+
+    #[serde(default)]
+    pub fields: BTreeMap<String, String>, // Custom values given when the project was created (e.g. from a batch file)
+
+    #[serde(default)]
+    pub school: Option<SchoolInfo>, // Semester dates and courses for School projects
+
+    #[serde(default)]
+    pub parent: Option<String>, // Folder name of the project this one was cut from (Shorts)
+
+    #[serde(default)]
+    pub podcast: Option<PodcastInfo>, // Episode number, speakers and feed id for Podcast projects
+
+    #[serde(default)]
+    pub series: Option<SeriesEpisode>, // Series folder and episode number for episodes of a Series project
+
+    #[serde(default)]
+    pub tags: Vec<String>, // Free-form lower case tags (sponsor, collab, course code, ...)
+
+    #[serde(default)]
+    pub library: Vec<LinkedAsset>, // Shared library assets placed into the project when it was created
+
+    #[serde(default)]
+    pub licenses: Vec<LicenseEntry>, // Source and license of the music and stock footage used
+
+    #[serde(default)]
+    pub files: Vec<FileRecord>, // Snapshot of the project contents right after creation
+}
+
+/*
+Purpose: Gets the folder the tool keeps its own data in (trash, settings, ...)
+Args: base_dir (Path) - base folder projects are created in
+Return: Path to the data folder (not created)
+*/
+pub fn app_dir(base_dir: &Path) -> PathBuf {
+    base_dir.join(APP_DIR)
+}
+
+/*
+Purpose: Finds the ideas markdown file created in a project ("<prefix>_ideas.md")
+Args: project (Path) - project folder
+Return: Path to the ideas file, or None if the project has none
+*/
+pub fn ideas_file(project: &Path) -> Option<PathBuf> {
+    fs::read_dir(project)
+        .ok()?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .find(|path| path.is_file() && path.file_name().is_some_and(|n| n.to_string_lossy().ends_with("_ideas.md")))
+}
+
+// Purpose: Reads the metadata of every folder directly inside a folder
+fn projects_in(dir: &Path) -> Vec<(PathBuf, ProjectMeta)> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    entries
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.is_dir())
+        .filter_map(|path| ProjectMeta::load(&path).ok().map(|meta| (path, meta)))
+        .collect()
+}
+
+/*
+Purpose: Finds every project folder inside the base folder that has metadata, including the episodes inside series
+Args: base_dir (Path) - base folder projects are created in
+Return: Project paths with their metadata, sorted by path
+*/
+pub fn list_projects(base_dir: &Path) -> Vec<(PathBuf, ProjectMeta)> {
+    let mut projects = projects_in(base_dir);
+
+    let series: Vec<PathBuf> = projects.iter().filter(|(_, meta)| meta.project_type == ProjectType::Series).map(|(p, _)| p.clone()).collect();
+    for series_dir in series {
+        projects.extend(projects_in(&series_dir));
+    }
+
+    projects.sort_by(|a, b| a.0.cmp(&b.0));
+    projects
+}
+
+impl ProjectMeta {
+    // Purpose: Creates metadata for a project that was just created
+    pub fn new(name: &str, project_type: ProjectType) -> Self {
+        ProjectMeta {
+            name: name.to_string(),
+            project_type,
+            created: Local::now(),
+            date: Local::now().date_naive(),
+            date_kind: DateKind::Created,
+            publish_date: None,
+            status: ProjectStatus::Planned,
+            fields: BTreeMap::new(),
+            school: None,
+            parent: None,
+            podcast: None,
+            series: None,
+            tags: Vec::new(),
+            library: Vec::new(),
+            licenses: Vec::new(),
+            files: Vec::new(),
+        }
+    }
+
+    // Purpose: Gets the project's folder relative to the base folder, using '/' ("Series/2026-01-31_E01 Name")
+    pub fn relative_path(&self) -> String {
+        match &self.series {
+            Some(episode) => format!("{}/{}", episode.series, self.name),
+            None => self.name.clone(),
+        }
+    }
+
+    // Purpose: Reads the metadata file of a project folder
+    pub fn load(project: &Path) -> io::Result<Self> {
+        let contents = fs::read_to_string(project.join(META_FILE))?;
+        Ok(serde_json::from_str(&contents)?)
+    }
+
+    // Purpose: Writes the metadata file into a project folder
+    pub fn save(&self, project: &Path) -> io::Result<()> {
+        let contents = serde_json::to_string_pretty(self)?;
+        fs::write(project.join(META_FILE), contents)
+    }
+}
@@ -0,0 +1,267 @@
+use std::{fs, io}; // OS crate
+use std::path::Path; // File path crate
+use serde::{Deserialize, Serialize}; // Stored in the settings per project type
+
+use crate::config::Editor;
+use crate::media::{self, FOOTAGE_FOLDERS}; // Clips already in these folders are added to the project
+use crate::premiere::xml_escape;
+
+const MLT_VERSION: &str = "7.22.0"; // MLT version written into new projects, both editors open older and newer files
+
+// Frame rates offered in the settings as numerator and denominator, NTSC rates are 1000/1001 of the whole number
+pub const FRAME_RATES: [(u32, u32); 8] = [(24000, 1001), (24, 1), (25, 1), (30000, 1001), (30, 1), (50, 1), (60000, 1001), (60, 1)];
+
+// Resolution and frame rate of a new editing project
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct VideoProfile {
+    pub width: u32, // Frame width in pixels
+    pub height: u32, // Frame height in pixels
+    pub frame_rate_num: u32, // Frames per second as a fraction, 30000/1001 for 29.97
+    pub frame_rate_den: u32,
+}
+
+impl VideoProfile {
+    // Purpose: Gets the frame rate as frames per second
+    pub fn fps(&self) -> f64 {
+        self.frame_rate_num as f64 / self.frame_rate_den as f64
+    }
+
+    // Purpose: Gets the frame rate as it is usually written, such as "30" or "29.97"
+    pub fn fps_label(&self) -> String {
+        if self.frame_rate_num.is_multiple_of(self.frame_rate_den) {
+            return (self.frame_rate_num / self.frame_rate_den).to_string();
+        }
+        format!("{:.3}", self.fps()).trim_end_matches('0').to_string()
+    }
+
+    // Purpose: Gets the profile's description such as "1920x1080 29.97 fps"
+    fn description(&self) -> String {
+        format!("{}x{} {} fps", self.width, self.height, self.fps_label())
+    }
+
+    // Purpose: Writes the MLT <profile> element
+    fn xml(&self) -> String {
+        let gcd = (1..=self.width.min(self.height)).rev().find(|d| self.width.is_multiple_of(*d) && self.height.is_multiple_of(*d)).unwrap_or(1);
+        format!(
+            " <profile description=\"{}\" width=\"{}\" height=\"{}\" progressive=\"1\" sample_aspect_num=\"1\" sample_aspect_den=\"1\" display_aspect_num=\"{}\" display_aspect_den=\"{}\" frame_rate_num=\"{}\" frame_rate_den=\"{}\" colorspace=\"709\"/>\n",
+            self.description(),
+            self.width,
+            self.height,
+            self.width / gcd,
+            self.height / gcd,
+            self.frame_rate_num,
+            self.frame_rate_den
+        )
+    }
+}
+
+// Purpose: Writes an MLT <property> element
+fn property(indent: &str, name: &str, value: &str) -> String {
+    format!("{}<property name=\"{}\">{}</property>\n", indent, name, xml_escape(value))
+}
+
+// Purpose: Writes the black producer every MLT timeline starts with
+fn black_producer(id: &str, playlist_id: Option<&str>) -> String {
+    let mut xml = format!(" <producer id=\"{}\" in=\"0\" out=\"0\">\n", id);
+    xml.push_str(&property("  ", "length", "2147483647"));
+    xml.push_str(&property("  ", "eof", "continue"));
+    xml.push_str(&property("  ", "resource", "black"));
+    xml.push_str(&property("  ", "aspect_ratio", "1"));
+    xml.push_str(&property("  ", "mlt_service", "color"));
+    xml.push_str(&property("  ", "mlt_image_format", "rgba"));
+    xml.push_str(&property("  ", "set.test_audio", "0"));
+    if let Some(playlist_id) = playlist_id {
+        xml.push_str(&property("  ", "kdenlive:playlistid", playlist_id));
+    }
+    xml.push_str(" </producer>\n");
+    xml
+}
+
+// Clip found in one of the footage folders when the project is written
+struct FolderClip {
+    folder: usize, // Index into FOOTAGE_FOLDERS
+    resource: String, // Path relative to the project folder using '/'
+    frames: u64, // Length in frames of the project's frame rate
+}
+
+// Purpose: Lists the clips already in the footage folders in recording order
+fn folder_clips(project_dir: &Path, profile: &VideoProfile) -> Vec<FolderClip> {
+    let (clips, _) = media::project_clips(project_dir);
+    clips
+        .into_iter()
+        .map(|(folder, clip)| FolderClip {
+            folder: FOOTAGE_FOLDERS.iter().position(|f| *f == folder).unwrap_or(0),
+            resource: format!("{}/{}", folder, clip.name()),
+            frames: ((clip.duration.unwrap_or(0.0) * profile.fps()).round() as u64).max(1),
+        })
+        .collect()
+}
+
+// Purpose: Writes the producer of one clip, extra holds editor specific properties
+fn clip_producer(id: &str, clip: &FolderClip, extra: &[(&str, String)]) -> String {
+    let mut xml = format!(" <producer id=\"{}\" in=\"0\" out=\"{}\">\n", id, clip.frames - 1);
+    xml.push_str(&property("  ", "length", &clip.frames.to_string()));
+    xml.push_str(&property("  ", "eof", "pause"));
+    xml.push_str(&property("  ", "resource", &clip.resource));
+    xml.push_str(&property("  ", "mlt_service", "avformat-novalidate"));
+    for (name, value) in extra {
+        xml.push_str(&property("  ", name, value));
+    }
+    xml.push_str(" </producer>\n");
+    xml
+}
+
+/*
+Purpose: Builds a Kdenlive project with one bin per footage folder holding the clips already in it, two video tracks and two audio tracks
+Args: project_dir (Path) - new project folder, used as the document root
+      profile (VideoProfile) - resolution and frame rate
+Return: Project XML
+*/
+fn kdenlive_xml(project_dir: &Path, profile: &VideoProfile) -> String {
+    let root = xml_escape(&project_dir.to_string_lossy());
+    let mut xml = format!(
+        "<?xml version='1.0' encoding='utf-8'?>\n<mlt LC_NUMERIC=\"C\" producer=\"main_bin\" version=\"{}\" root=\"{}\">\n",
+        MLT_VERSION, root
+    );
+    xml.push_str(&profile.xml());
+
+    // Clips go into their folder's bin through kdenlive:folderid, bin ids and clip ids share one range
+    let clips = folder_clips(project_dir, profile);
+    for (i, clip) in clips.iter().enumerate() {
+        let extra = [
+            ("kdenlive:id", (FOOTAGE_FOLDERS.len() + i + 1).to_string()),
+            ("kdenlive:folderid", (clip.folder + 1).to_string()),
+        ];
+        xml.push_str(&clip_producer(&format!("clip{}", i), clip, &extra));
+    }
+
+    // Bins are stored as "kdenlive:folder.<parent id>.<id>" properties, -1 being the root of the project bin
+    xml.push_str(" <playlist id=\"main_bin\">\n");
+    for (i, folder) in FOOTAGE_FOLDERS.iter().enumerate() {
+        xml.push_str(&property("  ", &format!("kdenlive:folder.-1.{}", i + 1), folder));
+    }
+    xml.push_str(&property("  ", "kdenlive:docproperties.version", "1.04"));
+    xml.push_str(&property("  ", "kdenlive:docproperties.projectfolder", &project_dir.join("Save").to_string_lossy()));
+    xml.push_str(&property("  ", "kdenlive:docproperties.profile", &profile.description()));
+    xml.push_str(&property("  ", "xml_retain", "1"));
+    for (i, clip) in clips.iter().enumerate() {
+        xml.push_str(&format!("  <entry producer=\"clip{}\" in=\"0\" out=\"{}\"/>\n", i, clip.frames - 1));
+    }
+    xml.push_str(" </playlist>\n");
+
+    xml.push_str(&black_producer("black_track", Some("black_track")));
+
+    // Every Kdenlive track is a tractor of two playlists, audio tracks first
+    let tracks = [("A2", true), ("A1", true), ("V1", false), ("V2", false)];
+    for (i, (name, audio)) in tracks.iter().enumerate() {
+        for half in 0..2 {
+            xml.push_str(&format!(" <playlist id=\"playlist{}\">\n", i * 2 + half));
+            if *audio {
+                xml.push_str(&property("  ", "kdenlive:audio_track", "1"));
+            }
+            xml.push_str(" </playlist>\n");
+        }
+
+        xml.push_str(&format!(" <tractor id=\"tractor{}\" in=\"0\">\n", i));
+        if *audio {
+            xml.push_str(&property("  ", "kdenlive:audio_track", "1"));
+        }
+        xml.push_str(&property("  ", "kdenlive:track_name", name));
+        let hide = if *audio { "video" } else { "audio" };
+        xml.push_str(&format!("  <track hide=\"{}\" producer=\"playlist{}\"/>\n", hide, i * 2));
+        xml.push_str(&format!("  <track hide=\"{}\" producer=\"playlist{}\"/>\n", hide, i * 2 + 1));
+        xml.push_str(" </tractor>\n");
+    }
+
+    xml.push_str(" <tractor id=\"maintractor\" in=\"0\">\n");
+    xml.push_str("  <track producer=\"black_track\"/>\n");
+    for i in 0..tracks.len() {
+        xml.push_str(&format!("  <track producer=\"tractor{}\"/>\n", i));
+    }
+    xml.push_str(" </tractor>\n</mlt>\n");
+    xml
+}
+
+/*
+Purpose: Builds a Shotcut project listing the clips already in the footage folders in its playlist,
+         with a video track per footage folder and one audio track
+Args: project_dir (Path) - new project folder, used as the document root
+      profile (VideoProfile) - resolution and frame rate
+Return: Project XML
+*/
+fn shotcut_xml(project_dir: &Path, profile: &VideoProfile) -> String {
+    let root = xml_escape(&project_dir.to_string_lossy());
+    let mut xml = format!(
+        "<?xml version=\"1.0\" standalone=\"no\"?>\n<mlt LC_NUMERIC=\"C\" version=\"{}\" title=\"Shotcut\" producer=\"main_bin\" root=\"{}\">\n",
+        MLT_VERSION, root
+    );
+    xml.push_str(&profile.xml());
+
+    // Shotcut has no bins, its playlist holds the clips captioned with the folder they are in
+    let clips = folder_clips(project_dir, profile);
+    for (i, clip) in clips.iter().enumerate() {
+        xml.push_str(&clip_producer(&format!("clip{}", i), clip, &[("shotcut:caption", clip.resource.clone())]));
+    }
+
+    xml.push_str(" <playlist id=\"main_bin\">\n");
+    xml.push_str(&property("  ", "xml_retain", "1"));
+    for (i, clip) in clips.iter().enumerate() {
+        xml.push_str(&format!("  <entry producer=\"clip{}\" in=\"0\" out=\"{}\"/>\n", i, clip.frames - 1));
+    }
+    xml.push_str(" </playlist>\n");
+
+    xml.push_str(&black_producer("black", None));
+    xml.push_str(" <playlist id=\"background\">\n  <entry producer=\"black\" in=\"0\" out=\"0\"/>\n </playlist>\n");
+
+    // The video tracks are named after the footage folders (B-roll on top)
+    let mut tracks: Vec<(String, bool)> = FOOTAGE_FOLDERS.iter().map(|f| (f.to_string(), false)).collect();
+    tracks.push(("Audio".to_string(), true));
+    for (i, (name, audio)) in tracks.iter().enumerate() {
+        xml.push_str(&format!(" <playlist id=\"playlist{}\">\n", i));
+        xml.push_str(&property("  ", if *audio { "shotcut:audio" } else { "shotcut:video" }, "1"));
+        xml.push_str(&property("  ", "shotcut:name", name));
+        xml.push_str(" </playlist>\n");
+    }
+
+    xml.push_str(" <tractor id=\"tractor0\" title=\"Shotcut\" in=\"0\">\n");
+    xml.push_str(&property("  ", "shotcut", "1"));
+    xml.push_str(&property("  ", "shotcut:projectAudioChannels", "2"));
+    xml.push_str(&property("  ", "shotcut:projectFolder", "1"));
+    xml.push_str("  <track producer=\"background\"/>\n");
+    for (i, (_, audio)) in tracks.iter().enumerate() {
+        let hide = if *audio { " hide=\"video\"" } else { "" };
+        xml.push_str(&format!("  <track producer=\"playlist{}\"{}/>\n", i, hide));
+    }
+    xml.push_str(" </tractor>\n</mlt>\n");
+    xml
+}
+
+/*
+Purpose: Writes a Kdenlive or Shotcut project for a new project folder
+Args: editor (Editor) - Kdenlive or Shotcut
+      target (Path) - project file to write
+      project_dir (Path) - new project folder
+      profile (VideoProfile) - resolution and frame rate
+Return: Error message if failed
+*/
+pub fn write_project(editor: Editor, target: &Path, project_dir: &Path, profile: &VideoProfile) -> io::Result<()> {
+    let project_dir = std::path::absolute(project_dir)?; // Clip paths are resolved from the document root
+    let xml = match editor {
+        Editor::Kdenlive => kdenlive_xml(&project_dir, profile),
+        Editor::Shotcut => shotcut_xml(&project_dir, profile),
+        Editor::Premiere => return Err(io::Error::new(io::ErrorKind::InvalidInput, "Premiere projects are not MLT files")),
+    };
+    fs::write(target, xml)
+}
+
+/*
+Purpose: Points the document root and project folder of a project at its folder after the folder was renamed
+Args: file (Path) - .kdenlive or .mlt file to update
+      from (Path) - old project folder
+      to (Path) - new project folder
+Return: Error message if failed
+*/
+pub fn move_project_paths(file: &Path, from: &Path, to: &Path) -> io::Result<()> {
+    let xml = fs::read_to_string(file)?;
+    fs::write(file, xml.replace(&xml_escape(&from.to_string_lossy()), &xml_escape(&to.to_string_lossy())))
+}
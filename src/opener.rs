@@ -0,0 +1,121 @@
+use std::{fs, io}; // OS crate
+use std::path::{Path, PathBuf}; // File path crate
+use std::process::Command; // Starts the file manager and other applications
+
+use crate::config::Config;
+use crate::metadata;
+
+pub const FOLDER_KEY: &str = "folder"; // Opener setting used for folders, files use their extension
+pub const OPENER_KEYS: [&str; 6] = [FOLDER_KEY, "md", "prproj", "kdenlive", "mlt", "psd"]; // Openers shown in the settings
+
+/*
+Purpose: Splits an opener command into its program and arguments, keeping "quoted parts" together
+Args: command (String) - command such as "\"C:\Program Files\App\app.exe\" --open {path}"
+Return: Program followed by its arguments
+*/
+fn split_command(command: &str) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut quoted = false;
+
+    for c in command.chars() {
+        match c {
+            '"' => quoted = !quoted,
+            c if c.is_whitespace() && !quoted => {
+                if !current.is_empty() {
+                    parts.push(std::mem::take(&mut current));
+                }
+            }
+            c => current.push(c),
+        }
+    }
+    if !current.is_empty() {
+        parts.push(current);
+    }
+    parts
+}
+
+// Purpose: Builds the command the operating system opens files with in their default application
+fn system_opener(path: &Path) -> Command {
+    if cfg!(windows) {
+        let mut command = Command::new("explorer");
+        command.arg(path);
+        command
+    } else if cfg!(target_os = "macos") {
+        let mut command = Command::new("open");
+        command.arg(path);
+        command
+    } else {
+        let mut command = Command::new("xdg-open");
+        command.arg(path);
+        command
+    }
+}
+
+/*
+Purpose: Opens a folder in the file manager or a file in its application, using the opener set for its type if there is one
+Args: path (Path) - folder or file to open
+      config (Config) - opener commands by file extension ("folder" for folders), {path} is replaced by the path
+Return: Error message if the application could not be started
+*/
+pub fn open_path(path: &Path, config: &Config) -> io::Result<()> {
+    if !path.exists() {
+        return Err(io::Error::new(io::ErrorKind::NotFound, format!("{} does not exist", path.to_string_lossy())));
+    }
+    let path = std::path::absolute(path)?; // Not canonicalized, explorer does not understand Windows' \\?\C:\... form
+
+    let key = if path.is_dir() {
+        FOLDER_KEY.to_string()
+    } else {
+        path.extension().map(|e| e.to_string_lossy().to_lowercase()).unwrap_or_default()
+    };
+    let parts = config.openers.get(&key).map(|command| split_command(command)).unwrap_or_default();
+
+    let mut command = match parts.split_first() {
+        Some((program, args)) => {
+            let mut command = Command::new(program);
+            let path_text = path.to_string_lossy();
+            command.args(args.iter().map(|a| a.replace("{path}", &path_text)));
+            if !args.iter().any(|a| a.contains("{path}")) {
+                command.arg(&path); // The path goes last when the command does not place it
+            }
+            command
+        }
+        None => system_opener(&path),
+    };
+
+    let mut child = command
+        .spawn()
+        .map_err(|e| io::Error::new(e.kind(), format!("Could not start {}: {}", command.get_program().to_string_lossy(), e)))?;
+
+    // The application keeps running on its own, it is only waited for on a thread so it does not stay a zombie once it exits
+    std::thread::spawn(move || {
+        let _ = child.wait();
+    });
+    Ok(())
+}
+
+/*
+Purpose: Finds the files made in a project that are worth opening directly
+Args: project (Path) - project folder
+Return: Button label and path of the ideas file, editor project and thumbnail PSD that exist
+*/
+pub fn key_files(project: &Path) -> Vec<(&'static str, PathBuf)> {
+    let first_with = |folder: &str, extensions: &[&str]| -> Option<PathBuf> {
+        let mut files: Vec<PathBuf> = fs::read_dir(project.join(folder)).ok()?.filter_map(|e| e.ok().map(|e| e.path())).collect();
+        files.sort();
+        files.into_iter().find(|p| p.extension().is_some_and(|e| extensions.contains(&e.to_string_lossy().to_lowercase().as_str())))
+    };
+
+    let mut files = Vec::new();
+    if let Some(ideas) = metadata::ideas_file(project) {
+        files.push(("Ideas", ideas));
+    }
+    if let Some(save) = first_with("Save", &["prproj", "kdenlive", "mlt"]) {
+        files.push(("Editor Project", save));
+    }
+    if let Some(psd) = first_with("Photoshop", &["psd"]) {
+        files.push(("Thumbnail", psd));
+    }
+    files
+}
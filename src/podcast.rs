@@ -0,0 +1,210 @@
+use std::{fs, io}; // OS crate
+use std::io::{Read, Seek, SeekFrom}; // Reads the start of MP3 files only
+use std::path::{Path, PathBuf}; // File path crate
+use chrono::NaiveDate; // Recording and publish dates
+use serde::{Deserialize, Serialize}; // Stored in the episode metadata
+
+use crate::config::Config;
+use crate::media;
+use crate::metadata::ProjectMeta;
+use crate::premiere::{new_uuid, xml_escape};
+use crate::school::sanitize;
+
+pub const DEFAULT_SPEAKER: &str = "Host"; // Track folder made when no hosts or guests are given
+const AUDIO_EXTENSIONS: [(&str, &str); 3] = [("mp3", "audio/mpeg"), ("m4a", "audio/x-m4a"), ("wav", "audio/wav")]; // Episode files and their MIME types
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PodcastInfo {
+    pub episode: u32, // Episode number, also the folder name prefix
+    pub speakers: Vec<String>, // Hosts and guests, each with their own track folder
+    pub guid: String, // Permanent id of the episode in the feed
+}
+
+impl PodcastInfo {
+    // Purpose: Creates episode info with a new feed id
+    pub fn new(episode: u32, speakers: Vec<String>) -> Self {
+        PodcastInfo { episode, speakers, guid: new_uuid() }
+    }
+
+    // Purpose: Gets the folder name of an episode such as "EP012_Name"
+    pub fn folder_name(&self, name: &str) -> String {
+        format!("EP{:03}_{}", self.episode, name)
+    }
+}
+
+// Purpose: Reads the episode number from a folder name such as "EP012_Name"
+pub fn episode_number(folder_name: &str) -> Option<u32> {
+    let rest = folder_name.get(..2).filter(|p| p.eq_ignore_ascii_case("EP")).map(|_| &folder_name[2..])?;
+    let digits: String = rest.chars().take_while(|c| c.is_ascii_digit()).collect();
+    digits.parse().ok()
+}
+
+/*
+Purpose: Finds the next free episode number by scanning the episode folders in the base folder
+Args: base_dir (Path) - base folder projects are created in
+Return: One more than the highest episode number found, 1 if there are none
+*/
+pub fn next_episode(base_dir: &Path) -> u32 {
+    fs::read_dir(base_dir)
+        .map(|entries| {
+            entries
+                .filter_map(|e| e.ok())
+                .filter(|e| e.path().is_dir())
+                .filter_map(|e| episode_number(&e.file_name().to_string_lossy()))
+                .max()
+                .unwrap_or(0)
+        })
+        .unwrap_or(0)
+        + 1
+}
+
+// Purpose: Splits a comma separated list of hosts and guests
+pub fn parse_speakers(text: &str) -> Vec<String> {
+    text.split(',').map(sanitize).filter(|s| !s.is_empty()).collect()
+}
+
+/*
+Purpose: Creates one track folder per host or guest and the show notes of a new episode
+Args: project (Path) - new episode folder
+      name (String) - episode title
+      date (Date) - recording date
+      info (PodcastInfo) - episode number and speakers
+Return: Error message if failed
+*/
+pub fn create_episode_files(project: &Path, name: &str, date: NaiveDate, info: &PodcastInfo) -> io::Result<()> {
+    let speakers = if info.speakers.is_empty() { vec![DEFAULT_SPEAKER.to_string()] } else { info.speakers.clone() };
+    for speaker in &speakers {
+        fs::create_dir_all(project.join("Audio").join(speaker))?;
+    }
+
+    let template = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("Assets").join("template_show_notes.md");
+    let notes = fs::read_to_string(template)?
+        .replace("{episode}", &info.episode.to_string())
+        .replace("{title}", name)
+        .replace("{date}", &date.format("%Y-%m-%d").to_string())
+        .replace("{speakers}", &speakers.join(", "));
+    fs::write(project.join("show_notes.md"), notes)
+}
+
+/*
+Purpose: Works out the length of an MP3 from its first frame, using the Xing/Info frame count when there is one
+Args: path (Path) - MP3 file
+Return: Length in seconds, or None if the file is not an MPEG layer III file
+*/
+fn mp3_duration(path: &Path) -> Option<f64> {
+    let mut file = fs::File::open(path).ok()?;
+    let file_len = file.metadata().ok()?.len();
+
+    // Skips the ID3v2 tag, its size is stored as four 7 bit bytes
+    let mut header = [0u8; 10];
+    file.read_exact(&mut header).ok()?;
+    let mut start = 0;
+    if &header[..3] == b"ID3" {
+        let size = header[6..10].iter().fold(0u64, |size, b| (size << 7) | (*b as u64 & 0x7f));
+        start = 10 + size + if header[5] & 0x10 != 0 { 10 } else { 0 };
+    }
+
+    let mut buffer = vec![0u8; 64 * 1024];
+    file.seek(SeekFrom::Start(start)).ok()?;
+    let read = file.read(&mut buffer).ok()?;
+    let buffer = &buffer[..read];
+
+    let sync = buffer.windows(4).position(|w| w[0] == 0xff && w[1] & 0xe0 == 0xe0 && (w[1] >> 1) & 3 == 1 && w[2] >> 4 != 0 && w[2] >> 4 != 15)?;
+    let frame = &buffer[sync..];
+    let version = (frame[1] >> 3) & 3; // 3 is MPEG 1, 2 is MPEG 2, 0 is MPEG 2.5
+    let mono = frame[3] >> 6 == 3;
+
+    const BITRATES_V1: [u64; 15] = [0, 32, 40, 48, 56, 64, 80, 96, 112, 128, 160, 192, 224, 256, 320];
+    const BITRATES_V2: [u64; 15] = [0, 8, 16, 24, 32, 40, 48, 56, 64, 80, 96, 112, 128, 144, 160];
+    let bitrate = if version == 3 { BITRATES_V1 } else { BITRATES_V2 }[(frame[2] >> 4) as usize] * 1000;
+    let sample_rate = [44100u64, 48000, 32000].get(((frame[2] >> 2) & 3) as usize)? >> match version {
+        3 => 0,
+        2 => 1,
+        _ => 2,
+    };
+    let samples_per_frame = if version == 3 { 1152 } else { 576 };
+
+    // A Xing/Info frame after the side information holds the real frame count of variable bitrate files
+    let side_info = match (version == 3, mono) {
+        (true, false) => 32,
+        (true, true) | (false, false) => 17,
+        (false, true) => 9,
+    };
+    let xing = frame.get(4 + side_info..4 + side_info + 12)?;
+    if (&xing[..4] == b"Xing" || &xing[..4] == b"Info") && xing[7] & 1 != 0 {
+        let frames = u32::from_be_bytes([xing[8], xing[9], xing[10], xing[11]]) as u64;
+        return Some((frames * samples_per_frame) as f64 / sample_rate as f64);
+    }
+
+    let audio_bytes = file_len.saturating_sub(start + sync as u64);
+    Some(audio_bytes as f64 * 8.0 / bitrate as f64)
+}
+
+// Purpose: Formats seconds as hh:mm:ss for <itunes:duration>
+fn itunes_duration(seconds: f64) -> String {
+    let total = seconds.round() as u64;
+    format!("{:02}:{:02}:{:02}", total / 3600, total / 60 % 60, total % 60)
+}
+
+// Purpose: Wraps text in CDATA, splitting any "]]>" it contains
+fn cdata(text: &str) -> String {
+    format!("<![CDATA[{}]]>", text.replace("]]>", "]]]]><![CDATA[>"))
+}
+
+/*
+Purpose: Writes an RSS <item> for an episode, using the first audio file in its Export folder as the enclosure
+Args: project (Path) - episode folder
+      config (Config) - web address the audio files are uploaded to
+Return: Path to the written rss_item.xml
+*/
+pub fn write_rss_item(project: &Path, config: &Config) -> io::Result<PathBuf> {
+    let meta = ProjectMeta::load(project)?;
+    let info = meta.podcast.as_ref().ok_or(io::Error::new(io::ErrorKind::NotFound, "Project is not a podcast episode"))?;
+
+    let export = project.join("Export");
+    let mut files: Vec<PathBuf> = fs::read_dir(&export)?.filter_map(|e| e.ok().map(|e| e.path())).collect();
+    files.sort();
+    let (audio, mime) = files
+        .iter()
+        .find_map(|path| {
+            let ext = path.extension()?.to_string_lossy().to_lowercase();
+            AUDIO_EXTENSIONS.iter().find(|(e, _)| *e == ext).map(|(_, mime)| (path.clone(), *mime))
+        })
+        .ok_or(io::Error::new(io::ErrorKind::NotFound, "No mp3, m4a or wav file in the Export folder"))?;
+
+    let file_name = audio.file_name().unwrap_or_default().to_string_lossy().to_string();
+    let length = fs::metadata(&audio)?.len();
+    let duration = match mime {
+        "audio/mpeg" => mp3_duration(&audio),
+        "audio/x-m4a" => media::read_clip(&audio).ok().and_then(|clip| clip.duration),
+        _ => None,
+    };
+
+    // Spaces and other unsafe characters are percent-encoded in the enclosure address
+    let encoded: String = file_name
+        .bytes()
+        .map(|b| if b.is_ascii_alphanumeric() || b"-_.~".contains(&b) { (b as char).to_string() } else { format!("%{:02X}", b) })
+        .collect();
+    let url = format!("{}/{}", config.podcast_url.trim().trim_end_matches('/'), encoded);
+
+    let title = meta.name.split_once('_').map(|(_, t)| t).unwrap_or(&meta.name);
+    let notes = fs::read_to_string(project.join("show_notes.md")).unwrap_or_default();
+    let published = meta.publish_date.unwrap_or(meta.date);
+
+    let mut item = String::from("<item>\n");
+    item.push_str(&format!("  <title>{}</title>\n", xml_escape(&format!("Episode {}: {}", info.episode, title))));
+    item.push_str(&format!("  <itunes:episode>{}</itunes:episode>\n", info.episode));
+    item.push_str("  <itunes:episodeType>full</itunes:episodeType>\n");
+    item.push_str(&format!("  <description>{}</description>\n", cdata(notes.trim())));
+    item.push_str(&format!("  <pubDate>{}</pubDate>\n", published.format("%a, %d %b %Y 00:00:00 +0000")));
+    item.push_str(&format!("  <enclosure url=\"{}\" length=\"{}\" type=\"{}\"/>\n", xml_escape(&url), length, mime));
+    if let Some(duration) = duration {
+        item.push_str(&format!("  <itunes:duration>{}</itunes:duration>\n", itunes_duration(duration)));
+    }
+    item.push_str(&format!("  <guid isPermaLink=\"false\">{}</guid>\n", xml_escape(&info.guid)));
+    item.push_str("</item>\n");
+
+    let path = export.join("rss_item.xml");
+    fs::write(&path, item)?;
+    Ok(path)
+}
@@ -0,0 +1,261 @@
+use std::{fs, io}; // OS crate
+use std::collections::hash_map::RandomState; // Random numbers for new ids
+use std::hash::{BuildHasher, Hasher}; // Turns the random state into numbers
+use std::io::{Read, Write}; // Streams the compressed project
+use std::path::Path; // File path crate
+use flate2::Compression; // Compression level of the written project
+use flate2::read::GzDecoder; // .prproj files are gzipped XML
+use flate2::write::GzEncoder;
+
+use crate::mlt::VideoProfile;
+
+const TICKS_PER_SECOND: u64 = 254_016_000_000; // Premiere's time unit, frame rates are stored as ticks per frame
+const BIN_CLASS_ID: &str = "dbe73bdf-ba0f-4a39-9b9c-0e5cf6b2e8b4"; // Premiere's class id of bin items
+
+// Scratch disk settings pointed into the project folder, with the subfolder they use
+const SCRATCH_DISKS: [(&str, &str); 5] = [
+    ("AutoSaveLocation0", "Save/Auto-Save"),
+    ("VideoPreviewLocation0", "Save/Previews"),
+    ("AudioPreviewLocation0", "Save/Previews"),
+    ("CapturedVideoLocation0", "A-roll"),
+    ("CapturedAudioLocation0", "A-roll"),
+];
+
+// Purpose: Makes a random version 4 UUID for new project and bin ids
+pub fn new_uuid() -> String {
+    let random = || {
+        let mut hasher = RandomState::new().build_hasher();
+        hasher.write_u128(std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).map(|d| d.as_nanos()).unwrap_or(0));
+        hasher.finish()
+    };
+    let bytes = ((random() as u128) << 64) | random() as u128;
+    let bytes = (bytes & !(0xf_u128 << 76) | (0x4 << 76)) & !(0x3_u128 << 62) | (0x2 << 62); // Version 4, RFC 4122 variant
+    let hex = format!("{:032x}", bytes);
+    format!("{}-{}-{}-{}-{}", &hex[..8], &hex[8..12], &hex[12..16], &hex[16..20], &hex[20..])
+}
+
+// Purpose: Escapes text for use inside an XML element or attribute
+pub fn xml_escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+// Purpose: Replaces the text of every <tag>...</tag> element
+fn set_tag(xml: &str, tag: &str, value: &str) -> String {
+    let open = format!("<{}>", tag);
+    let close = format!("</{}>", tag);
+    let mut out = String::with_capacity(xml.len());
+    let mut rest = xml;
+
+    while let Some(start) = rest.find(&open) {
+        let after_open = start + open.len();
+        let Some(end) = rest[after_open..].find(&close) else {
+            break;
+        };
+        out.push_str(&rest[..after_open]);
+        out.push_str(value);
+        rest = &rest[after_open + end..];
+    }
+
+    out.push_str(rest);
+    out
+}
+
+// Purpose: Gets the text of the first <tag>...</tag> element
+fn get_tag<'a>(xml: &'a str, tag: &str) -> Option<&'a str> {
+    let open = format!("<{}>", tag);
+    let start = xml.find(&open)? + open.len();
+    let end = xml[start..].find(&format!("</{}>", tag))?;
+    Some(&xml[start..start + end])
+}
+
+/*
+Purpose: Sets the frame size and rate of every <VideoSettings> object, which new sequences and previews start from
+Args: xml (String) - project XML
+      profile (VideoProfile) - resolution and frame rate
+Return: Updated XML
+*/
+fn set_video_settings(xml: &str, profile: &VideoProfile) -> String {
+    let mut out = String::with_capacity(xml.len());
+    let mut rest = xml;
+
+    // Only the objects themselves are changed, <VideoSettings ObjectRef="…"/> references have no body
+    while let Some(start) = rest.find("<VideoSettings ObjectID=") {
+        let Some(end) = rest[start..].find("</VideoSettings>").map(|e| start + e) else {
+            break;
+        };
+        let block = set_tag(&rest[start..end], "FrameSize", &format!("0,0,{},{}", profile.width, profile.height));
+        let block = set_tag(&block, "FrameRate", &(TICKS_PER_SECOND * profile.frame_rate_den as u64 / profile.frame_rate_num as u64).to_string());
+        let block = set_tag(&block, "PixelAspectRatio", "1,1");
+        out.push_str(&rest[..start]);
+        out.push_str(&block);
+        rest = &rest[end..];
+    }
+
+    out.push_str(rest);
+    out
+}
+
+// Purpose: Builds the XML of one empty bin
+fn bin_item(uid: &str, id: u64, name: &str) -> String {
+    format!(
+        "<BinProjectItem ObjectUID=\"{uid}\" ClassID=\"{BIN_CLASS_ID}\" Version=\"3\">
+\t\t<ProjectItem Version=\"1\">
+\t\t\t<Node Version=\"1\">
+\t\t\t\t<Properties Version=\"1\">
+\t\t\t\t</Properties>
+\t\t\t\t<ID>{id}</ID>
+\t\t\t</Node>
+\t\t\t<Name>{}</Name>
+\t\t</ProjectItem>
+\t\t<ProjectItemContainer Version=\"1\">
+\t\t</ProjectItemContainer>
+\t</BinProjectItem>",
+        xml_escape(name)
+    )
+}
+
+/*
+Purpose: Adds empty bins to the root of the project
+Args: xml (String) - project XML
+      names (String list) - bin names to add
+Return: Updated XML, or None if the project has no root bin
+*/
+fn add_bins(xml: &str, names: &[&str]) -> Option<String> {
+    let mut next_id: u64 = get_tag(xml, "NextID")?.trim().parse().ok()?;
+    let mut bins = String::new();
+    let mut items = Vec::new();
+
+    for name in names {
+        let uid = new_uuid();
+        let bin = bin_item(&uid, next_id, name);
+        next_id += 1;
+
+        bins.push('\t');
+        bins.push_str(&bin);
+        bins.push('\n');
+        items.push(uid);
+    }
+
+    // Lists the new bins in the root bin
+    let root = xml.find("<RootProjectItem ObjectUID=\"")?;
+    let root_end = root + xml[root..].find("</RootProjectItem>")?;
+    let root_xml = &xml[root..root_end];
+    let root_xml = match root_xml.find("</Items>") {
+        Some(items_end) => {
+            let existing = root_xml[..items_end].matches("<Item ").count();
+            let refs: String = items
+                .iter()
+                .enumerate()
+                .map(|(i, uid)| format!("\t<Item Index=\"{}\" ObjectURef=\"{}\"/>\n\t\t\t", existing + i, uid))
+                .collect();
+            format!("{}{}{}", &root_xml[..items_end], refs, &root_xml[items_end..])
+        }
+        None => {
+            let container_end = root_xml.find("</ProjectItemContainer>")?;
+            let refs: String = items
+                .iter()
+                .enumerate()
+                .map(|(i, uid)| format!("\t\t\t\t<Item Index=\"{}\" ObjectURef=\"{}\"/>\n", i, uid))
+                .collect();
+            format!(
+                "{}\t\t\t<Items Version=\"1\">\n{}\t\t\t</Items>\n\t\t{}",
+                root_xml[..container_end].trim_end_matches(['\t', ' ']),
+                refs,
+                &root_xml[container_end..]
+            )
+        }
+    };
+
+    let xml = format!("{}{}{}", &xml[..root], root_xml, &xml[root_end..]);
+    let xml = set_tag(&xml, "NextID", &next_id.to_string());
+    let end = xml.rfind("</PremiereData>")?;
+    Some(format!("{}{}{}", &xml[..end], bins, &xml[end..]))
+}
+
+/*
+Purpose: Writes a Premiere project for a new project folder from the template, giving it a new id,
+         pointing its scratch disks and auto-saves inside the project folder, setting its video size and optionally adding bins
+Args: template (Path) - template .prproj
+      target (Path) - .prproj to write
+      project_dir (Path) - new project folder
+      bins (String list) - bin names to add
+      profile (VideoProfile) - resolution and frame rate
+Return: Error message if failed
+*/
+pub fn write_project(template: &Path, target: &Path, project_dir: &Path, bins: &[&str], profile: &VideoProfile) -> io::Result<()> {
+    let mut xml = String::new();
+    GzDecoder::new(fs::File::open(template)?).read_to_string(&mut xml)?;
+
+    // Premiere resolves these paths on its own, a relative base folder would leave them pointing nowhere
+    let target = std::path::absolute(target)?;
+    let project_dir = std::path::absolute(project_dir)?;
+
+    // A copied project id makes Premiere treat every project as the same one
+    xml = set_tag(&xml, "MZ.Project.GUID", &new_uuid());
+    xml = set_tag(&xml, "project.settings.lastknowngoodprojectpath", &xml_escape(&target.to_string_lossy()));
+    xml = set_video_settings(&xml, profile);
+
+    for (tag, folder) in SCRATCH_DISKS {
+        let path = folder.split('/').fold(project_dir.clone(), |path, part| path.join(part));
+        fs::create_dir_all(&path)?;
+        xml = set_tag(&xml, tag, &xml_escape(&path.to_string_lossy()));
+    }
+
+    if !bins.is_empty() && let Some(with_bins) = add_bins(&xml, bins) {
+        xml = with_bins;
+    }
+
+    let mut encoder = GzEncoder::new(fs::File::create(target)?, Compression::default());
+    encoder.write_all(xml.as_bytes())?;
+    encoder.finish()?;
+
+    Ok(())
+}
+
+/*
+Purpose: Points the scratch disks and saved location of a project at its folder after the folder was renamed
+Args: file (Path) - .prproj to update
+      from (Path) - old project folder
+      to (Path) - new project folder
+Return: Error message if failed
+*/
+pub fn move_project_paths(file: &Path, from: &Path, to: &Path) -> io::Result<()> {
+    let mut xml = String::new();
+    GzDecoder::new(fs::File::open(file)?).read_to_string(&mut xml)?;
+    let xml = xml.replace(&xml_escape(&from.to_string_lossy()), &xml_escape(&to.to_string_lossy()));
+
+    let mut encoder = GzEncoder::new(fs::File::create(file)?, Compression::default());
+    encoder.write_all(xml.as_bytes())?;
+    encoder.finish()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Purpose: Reads the XML of the template shipped with the tool
+    fn shipped_template() -> String {
+        let template = Path::new(env!("CARGO_MANIFEST_DIR")).join("Assets").join("template.prproj");
+        let mut xml = String::new();
+        GzDecoder::new(fs::File::open(template).unwrap()).read_to_string(&mut xml).unwrap();
+        xml
+    }
+
+    #[test]
+    fn adds_bins_to_the_shipped_template() {
+        let xml = shipped_template();
+        let next_id: u64 = get_tag(&xml, "NextID").unwrap().trim().parse().unwrap();
+
+        let with_bins = add_bins(&xml, &["A-roll", "B-roll"]).unwrap();
+        assert_eq!(with_bins.matches("<BinProjectItem ObjectUID=").count(), xml.matches("<BinProjectItem ObjectUID=").count() + 2);
+        assert!(with_bins.contains("<Name>A-roll</Name>") && with_bins.contains("<Name>B-roll</Name>"));
+        assert_eq!(get_tag(&with_bins, "NextID").unwrap(), (next_id + 2).to_string());
+
+        // Both bins are listed in the root bin
+        let root = &with_bins[with_bins.find("<RootProjectItem ObjectUID=").unwrap()..];
+        let root = &root[..root.find("</RootProjectItem>").unwrap()];
+        assert_eq!(root.matches("<Item Index=").count(), 2);
+    }
+}
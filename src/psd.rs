@@ -0,0 +1,426 @@
+use std::{fs, io}; // OS crate
+use std::path::Path; // File path crate
+
+const BACKGROUND: [u8; 3] = [0x20, 0x20, 0x20]; // Dark gray background colour
+const TEXT: [u8; 3] = [0xff, 0xff, 0xff]; // Title text colour
+const GLYPH_WIDTH: usize = 5; // Pixels across each glyph of the built in font
+const GLYPH_HEIGHT: usize = 7; // Pixels down each glyph of the built in font
+
+/*
+Purpose: Gets the built in 5x7 pixel font glyph for a character, one byte per row with bit 4 as the left pixel
+Args: c (char) - character to draw (lowercase letters use the uppercase glyphs)
+Return: Glyph rows ('?' for characters the font does not have)
+*/
+fn glyph(c: char) -> [u8; GLYPH_HEIGHT] {
+    match c.to_ascii_uppercase() {
+        'A' => [0x0e, 0x11, 0x11, 0x1f, 0x11, 0x11, 0x11],
+        'B' => [0x1e, 0x11, 0x11, 0x1e, 0x11, 0x11, 0x1e],
+        'C' => [0x0e, 0x11, 0x10, 0x10, 0x10, 0x11, 0x0e],
+        'D' => [0x1e, 0x11, 0x11, 0x11, 0x11, 0x11, 0x1e],
+        'E' => [0x1f, 0x10, 0x10, 0x1e, 0x10, 0x10, 0x1f],
+        'F' => [0x1f, 0x10, 0x10, 0x1e, 0x10, 0x10, 0x10],
+        'G' => [0x0e, 0x11, 0x10, 0x17, 0x11, 0x11, 0x0f],
+        'H' => [0x11, 0x11, 0x11, 0x1f, 0x11, 0x11, 0x11],
+        'I' => [0x0e, 0x04, 0x04, 0x04, 0x04, 0x04, 0x0e],
+        'J' => [0x07, 0x02, 0x02, 0x02, 0x02, 0x12, 0x0c],
+        'K' => [0x11, 0x12, 0x14, 0x18, 0x14, 0x12, 0x11],
+        'L' => [0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x1f],
+        'M' => [0x11, 0x1b, 0x15, 0x15, 0x11, 0x11, 0x11],
+        'N' => [0x11, 0x11, 0x19, 0x15, 0x13, 0x11, 0x11],
+        'O' => [0x0e, 0x11, 0x11, 0x11, 0x11, 0x11, 0x0e],
+        'P' => [0x1e, 0x11, 0x11, 0x1e, 0x10, 0x10, 0x10],
+        'Q' => [0x0e, 0x11, 0x11, 0x11, 0x15, 0x12, 0x0d],
+        'R' => [0x1e, 0x11, 0x11, 0x1e, 0x14, 0x12, 0x11],
+        'S' => [0x0f, 0x10, 0x10, 0x0e, 0x01, 0x01, 0x1e],
+        'T' => [0x1f, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04],
+        'U' => [0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x0e],
+        'V' => [0x11, 0x11, 0x11, 0x11, 0x11, 0x0a, 0x04],
+        'W' => [0x11, 0x11, 0x11, 0x15, 0x15, 0x15, 0x0a],
+        'X' => [0x11, 0x11, 0x0a, 0x04, 0x0a, 0x11, 0x11],
+        'Y' => [0x11, 0x11, 0x0a, 0x04, 0x04, 0x04, 0x04],
+        'Z' => [0x1f, 0x01, 0x02, 0x04, 0x08, 0x10, 0x1f],
+        '0' => [0x0e, 0x11, 0x13, 0x15, 0x19, 0x11, 0x0e],
+        '1' => [0x04, 0x0c, 0x04, 0x04, 0x04, 0x04, 0x0e],
+        '2' => [0x0e, 0x11, 0x01, 0x02, 0x04, 0x08, 0x1f],
+        '3' => [0x1f, 0x02, 0x04, 0x02, 0x01, 0x11, 0x0e],
+        '4' => [0x02, 0x06, 0x0a, 0x12, 0x1f, 0x02, 0x02],
+        '5' => [0x1f, 0x10, 0x1e, 0x01, 0x01, 0x11, 0x0e],
+        '6' => [0x06, 0x08, 0x10, 0x1e, 0x11, 0x11, 0x0e],
+        '7' => [0x1f, 0x01, 0x02, 0x04, 0x08, 0x08, 0x08],
+        '8' => [0x0e, 0x11, 0x11, 0x0e, 0x11, 0x11, 0x0e],
+        '9' => [0x0e, 0x11, 0x11, 0x0f, 0x01, 0x02, 0x0c],
+        ' ' => [0x00; GLYPH_HEIGHT],
+        '-' => [0x00, 0x00, 0x00, 0x1f, 0x00, 0x00, 0x00],
+        '_' => [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1f],
+        '.' => [0x00, 0x00, 0x00, 0x00, 0x00, 0x0c, 0x0c],
+        ',' => [0x00, 0x00, 0x00, 0x00, 0x0c, 0x04, 0x08],
+        ':' => [0x00, 0x0c, 0x0c, 0x00, 0x0c, 0x0c, 0x00],
+        '!' => [0x04, 0x04, 0x04, 0x04, 0x04, 0x00, 0x04],
+        '\'' => [0x0c, 0x04, 0x08, 0x00, 0x00, 0x00, 0x00],
+        '(' => [0x02, 0x04, 0x08, 0x08, 0x08, 0x04, 0x02],
+        ')' => [0x08, 0x04, 0x02, 0x02, 0x02, 0x04, 0x08],
+        _ => [0x0e, 0x11, 0x01, 0x02, 0x04, 0x00, 0x04],
+    }
+}
+
+// Purpose: Splits the title into lines of at most max_chars characters, breaking between words where possible
+fn wrap(title: &str, max_chars: usize) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    let mut line = String::new();
+
+    for word in title.split_whitespace() {
+        let mut word = word.to_string();
+        while word.chars().count() > max_chars {
+            let rest = word.split_off(word.char_indices().nth(max_chars).map(|(i, _)| i).unwrap_or(word.len()));
+            if !line.is_empty() {
+                lines.push(std::mem::take(&mut line));
+            }
+            lines.push(std::mem::replace(&mut word, rest));
+        }
+
+        if !line.is_empty() && line.chars().count() + 1 + word.chars().count() > max_chars {
+            lines.push(std::mem::take(&mut line));
+        }
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line.push_str(&word);
+    }
+
+    if !line.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+/*
+Purpose: Draws the title centred on a transparent canvas with the built in font, as large as fits
+Args: title (String) - text to draw
+      width (usize) - canvas width
+      height (usize) - canvas height
+Return: Alpha mask of the text, one byte per pixel (255 where text is)
+*/
+fn render_title(title: &str, width: usize, height: usize) -> Vec<u8> {
+    let mut mask = vec![0u8; width * height];
+    let margin = width / 10;
+
+    // Biggest scale where the wrapped title fits inside the margins
+    let mut scale = 12;
+    let lines = loop {
+        let max_chars = ((width - 2 * margin) / ((GLYPH_WIDTH + 1) * scale)).max(1);
+        let lines = wrap(title, max_chars);
+        let text_height = lines.len() * (GLYPH_HEIGHT + 2) * scale;
+        if scale == 1 || text_height <= height - 2 * margin {
+            break lines;
+        }
+        scale -= 1;
+    };
+
+    let line_height = (GLYPH_HEIGHT + 2) * scale;
+    let top = height.saturating_sub(lines.len() * line_height) / 2;
+
+    for (row, line) in lines.iter().enumerate() {
+        let line_width = line.chars().count() * (GLYPH_WIDTH + 1) * scale;
+        let left = width.saturating_sub(line_width) / 2;
+
+        for (col, c) in line.chars().enumerate() {
+            for (gy, bits) in glyph(c).iter().enumerate() {
+                for gx in 0..GLYPH_WIDTH {
+                    if bits & (0x10 >> gx) == 0 {
+                        continue;
+                    }
+                    // Each font pixel becomes a scale x scale block
+                    for dy in 0..scale {
+                        let y = top + row * line_height + gy * scale + dy;
+                        let x = left + col * (GLYPH_WIDTH + 1) * scale + gx * scale;
+                        if y < height && x + scale <= width {
+                            mask[y * width + x..y * width + x + scale].fill(255);
+                        }
+                    }
+                }
+            }
+        }
+    }
+    mask
+}
+
+// Purpose: Compresses one row of a channel with PackBits, the run length encoding PSD files use
+fn packbits(row: &[u8]) -> Vec<u8> {
+    let mut out = Vec::new();
+    let mut i = 0;
+
+    while i < row.len() {
+        let mut run = 1;
+        while i + run < row.len() && run < 128 && row[i + run] == row[i] {
+            run += 1;
+        }
+
+        if run >= 2 {
+            out.push((257 - run) as u8); // -(run - 1) as a signed byte
+            out.push(row[i]);
+            i += run;
+        } else {
+            let start = i;
+            while i < row.len() && i - start < 128 && !(i + 1 < row.len() && row[i] == row[i + 1]) {
+                i += 1;
+            }
+            out.push((i - start - 1) as u8);
+            out.extend_from_slice(&row[start..i]);
+        }
+    }
+    out
+}
+
+// Purpose: Compresses a whole channel, giving the per row byte counts and the packed data
+fn compress_channel(channel: &[u8], width: usize) -> (Vec<u16>, Vec<u8>) {
+    let mut counts = Vec::new();
+    let mut data = Vec::new();
+    for row in channel.chunks(width) {
+        let packed = packbits(row);
+        counts.push(packed.len() as u16);
+        data.extend(packed);
+    }
+    (counts, data)
+}
+
+// Purpose: Gets a layer name as a Pascal string padded to a multiple of 4 bytes
+fn pascal_name(name: &str) -> Vec<u8> {
+    let ascii: Vec<u8> = name.chars().take(255).map(|c| if c.is_ascii() { c as u8 } else { b'?' }).collect();
+    let mut out = vec![ascii.len() as u8];
+    out.extend(ascii);
+    while !out.len().is_multiple_of(4) {
+        out.push(0);
+    }
+    out
+}
+
+// Purpose: Gets the "luni" extra layer info block holding the full Unicode layer name
+fn unicode_name(name: &str) -> Vec<u8> {
+    let utf16: Vec<u16> = name.encode_utf16().collect();
+    let mut data = (utf16.len() as u32).to_be_bytes().to_vec();
+    for unit in utf16 {
+        data.extend(unit.to_be_bytes());
+    }
+    while !data.len().is_multiple_of(4) {
+        data.push(0);
+    }
+
+    let mut out = b"8BIMluni".to_vec();
+    out.extend((data.len() as u32).to_be_bytes());
+    out.extend(data);
+    out
+}
+
+// Purpose: Gets the "grid and guides" image resource with guides on the thirds of the canvas
+fn guides_resource(width: usize, height: usize) -> Vec<u8> {
+    let guides: [(usize, u8); 4] = [(width / 3, 0), (width * 2 / 3, 0), (height / 3, 1), (height * 2 / 3, 1)];
+
+    let mut data = Vec::new();
+    data.extend(1u32.to_be_bytes()); // Version
+    data.extend((576u32).to_be_bytes()); // Grid spacing, same as Photoshop's default
+    data.extend((576u32).to_be_bytes());
+    data.extend((guides.len() as u32).to_be_bytes());
+    for (position, direction) in guides {
+        data.extend(((position * 32) as u32).to_be_bytes()); // Positions are in 1/32 pixels
+        data.push(direction); // 0 vertical, 1 horizontal
+    }
+    if !data.len().is_multiple_of(2) {
+        data.push(0);
+    }
+
+    let mut out = b"8BIM".to_vec();
+    out.extend(1032u16.to_be_bytes());
+    out.extend([0, 0]); // Empty name
+    out.extend((data.len() as u32).to_be_bytes());
+    out.extend(data);
+    out
+}
+
+// One layer covering the whole canvas
+struct Layer {
+    name: String, // Layer name shown in Photoshop
+    channels: Vec<(i16, Vec<u8>)>, // Channel id (-1 alpha, 0 red, 1 green, 2 blue) and its pixels
+}
+
+// Purpose: Gets the layer record and compressed channel data of a layer
+fn layer_parts(layer: &Layer, width: usize, height: usize) -> (Vec<u8>, Vec<u8>) {
+    let mut record = Vec::new();
+    let mut channel_data = Vec::new();
+
+    record.extend(0i32.to_be_bytes()); // Top
+    record.extend(0i32.to_be_bytes()); // Left
+    record.extend((height as i32).to_be_bytes()); // Bottom
+    record.extend((width as i32).to_be_bytes()); // Right
+    record.extend((layer.channels.len() as u16).to_be_bytes());
+
+    for (id, pixels) in &layer.channels {
+        let (counts, data) = compress_channel(pixels, width);
+        let start = channel_data.len();
+        channel_data.extend(1u16.to_be_bytes()); // PackBits compression
+        for count in counts {
+            channel_data.extend(count.to_be_bytes());
+        }
+        channel_data.extend(data);
+
+        record.extend(id.to_be_bytes());
+        record.extend(((channel_data.len() - start) as u32).to_be_bytes());
+    }
+
+    record.extend(b"8BIMnorm"); // Normal blend mode
+    record.extend([255, 0, 0, 0]); // Opacity, clipping, flags, filler
+
+    let mut extra = Vec::new();
+    extra.extend(0u32.to_be_bytes()); // No layer mask
+    extra.extend(0u32.to_be_bytes()); // No blending ranges
+    extra.extend(pascal_name(&layer.name));
+    extra.extend(unicode_name(&layer.name));
+    record.extend((extra.len() as u32).to_be_bytes());
+    record.extend(extra);
+
+    (record, channel_data)
+}
+
+/*
+Purpose: Writes a layered Photoshop file with a background layer and a title layer, plus guides on the thirds
+Args: path (Path) - .psd file to write
+      width (usize) - canvas width in pixels
+      height (usize) - canvas height in pixels
+      title (String) - project title drawn on the title layer and used as its name
+Return: Error message if failed
+*/
+pub fn write_thumbnail(path: &Path, width: usize, height: usize, title: &str) -> io::Result<()> {
+    let pixels = width * height;
+    let mask = render_title(title, width, height);
+
+    let background = Layer {
+        name: "Background".to_string(),
+        channels: (0..3).map(|c| (c as i16, vec![BACKGROUND[c]; pixels])).collect(),
+    };
+    let mut title_channels = vec![(-1i16, mask.clone())];
+    title_channels.extend((0..3).map(|c| (c as i16, vec![TEXT[c]; pixels])));
+    let title_layer = Layer { name: format!("Title: {}", title), channels: title_channels };
+
+    let mut out = Vec::new();
+
+    // File header
+    out.extend(b"8BPS");
+    out.extend(1u16.to_be_bytes()); // Version
+    out.extend([0u8; 6]); // Reserved
+    out.extend(3u16.to_be_bytes()); // Channels in the merged image
+    out.extend((height as u32).to_be_bytes());
+    out.extend((width as u32).to_be_bytes());
+    out.extend(8u16.to_be_bytes()); // Bits per channel
+    out.extend(3u16.to_be_bytes()); // RGB colour mode
+
+    // Colour mode data (none for RGB)
+    out.extend(0u32.to_be_bytes());
+
+    // Image resources
+    let resources = guides_resource(width, height);
+    out.extend((resources.len() as u32).to_be_bytes());
+    out.extend(resources);
+
+    // Layer and mask information, records first and then every layer's channel data
+    let mut layer_info = Vec::new();
+    let mut all_channel_data = Vec::new();
+    layer_info.extend(2i16.to_be_bytes()); // Layer count, bottom layer first
+    for layer in [&background, &title_layer] {
+        let (record, channel_data) = layer_parts(layer, width, height);
+        layer_info.extend(record);
+        all_channel_data.extend(channel_data);
+    }
+    layer_info.extend(all_channel_data);
+    if !layer_info.len().is_multiple_of(2) {
+        layer_info.push(0);
+    }
+
+    out.extend(((layer_info.len() + 8) as u32).to_be_bytes());
+    out.extend((layer_info.len() as u32).to_be_bytes());
+    out.extend(layer_info);
+    out.extend(0u32.to_be_bytes()); // No global layer mask
+
+    // Merged image, used by apps that do not read layers
+    let merged: Vec<Vec<u8>> = (0..3)
+        .map(|c| mask.iter().map(|a| if *a > 0 { TEXT[c] } else { BACKGROUND[c] }).collect())
+        .collect();
+    let compressed: Vec<(Vec<u16>, Vec<u8>)> = merged.iter().map(|channel| compress_channel(channel, width)).collect();
+    out.extend(1u16.to_be_bytes()); // PackBits compression
+    for (counts, _) in &compressed {
+        for count in counts {
+            out.extend(count.to_be_bytes());
+        }
+    }
+    for (_, data) in &compressed {
+        out.extend(data);
+    }
+
+    fs::write(path, out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Purpose: Expands PackBits data the way Photoshop reads it
+    fn unpack(data: &[u8]) -> Vec<u8> {
+        let mut out = Vec::new();
+        let mut i = 0;
+        while i < data.len() {
+            let header = data[i] as i8;
+            if header >= 0 {
+                let count = header as usize + 1;
+                out.extend_from_slice(&data[i + 1..i + 1 + count]);
+                i += 1 + count;
+            } else {
+                out.extend(std::iter::repeat_n(data[i + 1], (1 - header as i16) as usize));
+                i += 2;
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn packs_runs_and_literals() {
+        assert!(packbits(&[]).is_empty());
+        assert_eq!(packbits(&[7]), vec![0, 7]);
+        assert_eq!(packbits(&[5, 5, 5, 5]), vec![253, 5]);
+        assert_eq!(packbits(&[1, 2, 3, 3, 3]), vec![1, 1, 2, 254, 3]);
+    }
+
+    #[test]
+    fn splits_runs_and_literals_longer_than_128() {
+        let run = vec![9u8; 300];
+        let packed = packbits(&run);
+        assert_eq!(packed, vec![129, 9, 129, 9, 213, 9]);
+        assert_eq!(unpack(&packed), run);
+
+        let literal: Vec<u8> = (0..300).map(|i| (i % 251) as u8).collect();
+        let packed = packbits(&literal);
+        assert_eq!(packed[0], 127);
+        assert_eq!(unpack(&packed), literal);
+    }
+
+    #[test]
+    fn round_trips_mixed_rows() {
+        let row: Vec<u8> = (0..1280u32).map(|i| if (i / 37) % 3 == 0 { 255 } else { (i * 7 % 5) as u8 }).collect();
+        assert_eq!(unpack(&packbits(&row)), row);
+
+        let (counts, data) = compress_channel(&[row.clone(), row.clone()].concat(), row.len());
+        assert_eq!(counts.len(), 2);
+        assert_eq!(counts.iter().map(|c| *c as usize).sum::<usize>(), data.len());
+    }
+
+    #[test]
+    fn wraps_titles_between_words() {
+        assert_eq!(wrap("My first drone flight", 10), vec!["My first", "drone", "flight"]);
+        assert_eq!(wrap("Supercalifragilistic", 8), vec!["Supercal", "ifragili", "stic"]);
+        assert!(wrap("   ", 10).is_empty());
+    }
+
+    #[test]
+    fn pads_layer_names() {
+        assert_eq!(pascal_name("Title"), vec![5, b'T', b'i', b't', b'l', b'e', 0, 0]);
+        assert_eq!(pascal_name("Déjà").len() % 4, 0);
+        assert_eq!(pascal_name("Déjà")[1..5], *b"D?j?");
+    }
+}
@@ -0,0 +1,285 @@
+use std::{fs, io}; // OS crate
+use std::path::{Path, PathBuf}; // File path crate
+use chrono::{DateTime, Duration, Local, NaiveDate}; // Semester date range and lecture weeks
+use serde::{Deserialize, Serialize}; // Stored in the semester metadata
+
+use crate::ics::{self, IcsEvent};
+use crate::metadata::ProjectMeta;
+
+pub const COURSE_SUBFOLDERS: [&str; 5] = ["Lectures", "HW", "Slides", "Exam", "Notes"]; // Folders every course starts with
+pub const DEFAULT_ASSIGNMENT_PATTERN: &str = "{due}_{title}"; // Assignment folder name, see assignment_folder_name
+const MAX_WEEKS: i64 = 53; // Longest date range lecture folders are made for
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Course {
+    pub code: String, // Course code such as CS101
+    pub name: String, // Course title (may be empty)
+
+    #[serde(default)]
+    pub submission_pattern: Option<String>, // Course's own submission zip name, see submission::zip_name
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Assignment {
+    pub title: String, // Assignment title such as "Lab 3"
+    pub course: String, // Code of the course it belongs to
+    pub due: NaiveDate, // Due date
+    pub folder: String, // Folder inside the semester, relative path using '/'
+
+    #[serde(default)]
+    pub submitted: Option<DateTime<Local>>, // When the submission zip was last made
+
+    #[serde(default)]
+    pub submission: Option<String>, // File name of the submission zip
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SchoolInfo {
+    pub start: Option<NaiveDate>, // First day of the semester
+    pub end: Option<NaiveDate>, // Last day of the semester
+
+    #[serde(default)]
+    pub courses: Vec<Course>, // Courses added to the semester
+
+    #[serde(default)]
+    pub assignments: Vec<Assignment>, // Registered assignments, each with a folder under its course's HW
+
+    #[serde(default = "default_assignment_pattern")]
+    pub assignment_pattern: String, // Folder name pattern for new assignments
+}
+
+// Purpose: Gives semesters saved without a pattern the default one
+fn default_assignment_pattern() -> String {
+    DEFAULT_ASSIGNMENT_PATTERN.to_string()
+}
+
+impl SchoolInfo {
+    // Purpose: Creates semester info with the given dates and courses
+    pub fn new(start: Option<NaiveDate>, end: Option<NaiveDate>, courses: Vec<Course>) -> Self {
+        SchoolInfo {
+            start,
+            end,
+            courses,
+            assignments: Vec::new(),
+            assignment_pattern: default_assignment_pattern(),
+        }
+    }
+
+    // Purpose: Gets the assignments sorted by due date, then course
+    pub fn assignments_by_due(&self) -> Vec<&Assignment> {
+        let mut sorted: Vec<&Assignment> = self.assignments.iter().collect();
+        sorted.sort_by(|a, b| a.due.cmp(&b.due).then(a.course.cmp(&b.course)));
+        sorted
+    }
+}
+
+// Purpose: Replaces characters that are not allowed in Windows folder names
+pub fn sanitize(name: &str) -> String {
+    name.trim()
+        .chars()
+        .map(|c| if matches!(c, '<' | '>' | ':' | '"' | '/' | '\\' | '|' | '?' | '*') { '-' } else { c })
+        .collect()
+}
+
+/*
+Purpose: Fills in an assignment folder pattern
+Args: pattern (String) - pattern using {title}, {course}, {due} (YYYY-MM-DD) and {n} (assignment number in the course)
+      title (String) - assignment title
+      course (String) - course code
+      due (Date) - due date
+      number (usize) - number of the assignment within its course
+Return: Folder name with unsafe characters replaced
+*/
+pub fn assignment_folder_name(pattern: &str, title: &str, course: &str, due: NaiveDate, number: usize) -> String {
+    let name = pattern
+        .replace("{title}", title.trim())
+        .replace("{course}", course)
+        .replace("{due}", &due.format("%Y-%m-%d").to_string())
+        .replace("{n}", &format!("{:02}", number));
+    sanitize(&name)
+}
+
+impl Course {
+    // Purpose: Reads a course from text such as "CS101 Intro to Programming" (first word is the code)
+    pub fn parse(line: &str) -> Option<Self> {
+        let line = line.trim();
+        let (code, name) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+        if code.is_empty() {
+            return None;
+        }
+        Some(Course { code: code.to_string(), name: name.trim().to_string(), submission_pattern: None })
+    }
+
+    // Purpose: Gets the folder name of the course inside its semester, with unsafe characters replaced
+    pub fn folder_name(&self) -> String {
+        if self.name.is_empty() {
+            sanitize(&self.code)
+        } else {
+            format!("{}_{}", sanitize(&self.code), sanitize(&self.name))
+        }
+    }
+}
+
+/*
+Purpose: Gets the weekly lecture folder names for a semester date range
+Args: start (Date) - first day of the semester
+      end (Date) - last day of the semester
+Return: Folder names like "Week01_2026-01-12", or an error if the range is invalid
+*/
+pub fn lecture_weeks(start: NaiveDate, end: NaiveDate) -> io::Result<Vec<String>> {
+    if end < start {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, "Semester ends before it starts"));
+    }
+    if (end - start).num_weeks() >= MAX_WEEKS {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, "Semester is longer than a year"));
+    }
+
+    let mut weeks = Vec::new();
+    let mut day = start;
+    while day <= end {
+        weeks.push(format!("Week{:02}_{}", weeks.len() + 1, day.format("%Y-%m-%d")));
+        day += Duration::weeks(1);
+    }
+    Ok(weeks)
+}
+
+/*
+Purpose: Creates a course folder with its subfolders and weekly lecture folders
+Args: semester (Path) - semester project folder
+      course (Course) - course to create
+      info (SchoolInfo) - semester dates used for the lecture weeks
+Return: Path to the course folder
+*/
+pub fn create_course_folders(semester: &Path, course: &Course, info: &SchoolInfo) -> io::Result<PathBuf> {
+    let course_dir = semester.join(course.folder_name());
+    if course_dir.exists() {
+        return Err(io::Error::new(io::ErrorKind::AlreadyExists, format!("Course '{}' already exists", course.code)));
+    }
+
+    let weeks = match (info.start, info.end) {
+        (Some(start), Some(end)) => lecture_weeks(start, end)?,
+        _ => Vec::new(),
+    };
+
+    fs::create_dir(&course_dir)?;
+    for sub in COURSE_SUBFOLDERS {
+        fs::create_dir(course_dir.join(sub))?;
+    }
+    for week in weeks {
+        fs::create_dir(course_dir.join("Lectures").join(week))?;
+    }
+
+    Ok(course_dir)
+}
+
+/*
+Purpose: Adds a course to an existing semester and records it in the semester metadata
+Args: semester (Path) - semester project folder
+      course (Course) - course to add
+Return: Path to the new course folder
+*/
+pub fn add_course(semester: &Path, course: &Course) -> io::Result<PathBuf> {
+    let mut meta = ProjectMeta::load(semester)?;
+    let info = meta.school.get_or_insert(SchoolInfo::new(None, None, Vec::new()));
+
+    if info.courses.iter().any(|c| c.code.eq_ignore_ascii_case(&course.code)) {
+        return Err(io::Error::new(io::ErrorKind::AlreadyExists, format!("Course '{}' already exists", course.code)));
+    }
+
+    let course_dir = create_course_folders(semester, course, info)?;
+    info.courses.push(course.clone());
+    meta.save(semester)?;
+
+    Ok(course_dir)
+}
+
+/*
+Purpose: Sets the submission zip naming convention of one course
+Args: semester (Path) - semester project folder
+      course_code (String) - code of a course in the semester
+      pattern (String) - zip name pattern, empty to use the default from the settings
+Return: Error message if failed
+*/
+pub fn set_submission_pattern(semester: &Path, course_code: &str, pattern: &str) -> io::Result<()> {
+    let mut meta = ProjectMeta::load(semester)?;
+    let info = meta.school.as_mut().ok_or(io::Error::new(io::ErrorKind::NotFound, "Project is not a semester"))?;
+    let course = info
+        .courses
+        .iter_mut()
+        .find(|c| c.code.eq_ignore_ascii_case(course_code))
+        .ok_or(io::Error::new(io::ErrorKind::NotFound, format!("Course '{}' is not in this semester", course_code)))?;
+
+    course.submission_pattern = (!pattern.trim().is_empty()).then(|| pattern.trim().to_string());
+    meta.save(semester)
+}
+
+/*
+Purpose: Registers an assignment and creates its folder under the course's HW folder
+Args: semester (Path) - semester project folder
+      course_code (String) - code of a course in the semester
+      title (String) - assignment title
+      due (Date) - due date
+      pattern (String) - folder name pattern saved as the semester's pattern, None uses the semester's
+Return: Path to the assignment folder
+*/
+pub fn add_assignment(semester: &Path, course_code: &str, title: &str, due: NaiveDate, pattern: Option<&str>) -> io::Result<PathBuf> {
+    if title.trim().is_empty() {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, "Assignment title cannot be empty"));
+    }
+
+    let mut meta = ProjectMeta::load(semester)?;
+    let info = meta.school.as_mut().ok_or(io::Error::new(io::ErrorKind::NotFound, "Project is not a semester"))?;
+    if let Some(pattern) = pattern {
+        info.assignment_pattern = pattern.to_string();
+    }
+    let course = info
+        .courses
+        .iter()
+        .find(|c| c.code.eq_ignore_ascii_case(course_code))
+        .ok_or(io::Error::new(io::ErrorKind::NotFound, format!("Course '{}' is not in this semester", course_code)))?;
+
+    let number = info.assignments.iter().filter(|a| a.course == course.code).count() + 1;
+    let folder = format!("{}/HW/{}", course.folder_name(), assignment_folder_name(&info.assignment_pattern, title, &course.code, due, number));
+    let path = semester.join(&folder);
+    if path.exists() {
+        return Err(io::Error::new(io::ErrorKind::AlreadyExists, format!("Folder '{}' already exists", folder)));
+    }
+    fs::create_dir_all(&path)?;
+
+    info.assignments.push(Assignment {
+        title: title.trim().to_string(),
+        course: course.code.clone(),
+        due,
+        folder,
+        submitted: None,
+        submission: None,
+    });
+    meta.save(semester)?;
+
+    Ok(path)
+}
+
+/*
+Purpose: Exports a semester's assignments as an .ics calendar inside the semester folder
+Args: semester (Path) - semester project folder
+Return: Path to the written calendar file
+*/
+pub fn export_assignments_ics(semester: &Path) -> io::Result<PathBuf> {
+    let meta = ProjectMeta::load(semester)?;
+    let info = meta.school.as_ref().ok_or(io::Error::new(io::ErrorKind::NotFound, "Project is not a semester"))?;
+
+    let events: Vec<IcsEvent> = info
+        .assignments_by_due()
+        .into_iter()
+        .map(|a| IcsEvent {
+            uid: format!("{}-{}@y_template", meta.name, a.folder).replace(' ', "_"),
+            date: a.due,
+            summary: format!("{}: {} due", a.course, a.title),
+            description: format!("Folder: {}", a.folder),
+        })
+        .collect();
+
+    let path = semester.join(format!("{}_assignments.ics", meta.name));
+    ics::write_calendar(&path, &format!("{} assignments", meta.name), &events)?;
+    Ok(path)
+}
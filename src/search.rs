@@ -0,0 +1,270 @@
+use std::{fs, io}; // OS crate
+use std::collections::BTreeMap; // Sorted key/value storage
+use std::path::{Path, PathBuf}; // File path crate
+use std::time::UNIX_EPOCH; // Converts modified times into plain numbers
+use serde::{Deserialize, Serialize}; // The index is stored as JSON
+
+use crate::metadata::{self, META_FILE};
+
+const INDEX_FILE: &str = "search_index.json"; // Search index inside the tool's data folder
+const TEXT_EXTENSIONS: [&str; 2] = ["md", "txt"]; // Files indexed for search (ideas files, scripts, show notes)
+const MAX_FILE_SIZE: u64 = 1024 * 1024; // Bigger text files are left out (logs, exports)
+const SNIPPET_CHARS: usize = 40; // Characters shown on each side of a match
+const MAX_SNIPPETS: usize = 3; // Matches shown per file
+
+// Text of one indexed file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexedFile {
+    pub project: String, // Project the file belongs to, relative to the base folder
+    pub modified: u64, // Modified time in seconds since 1970 when it was read
+    pub size: u64, // File size when it was read
+    pub text: String, // File contents
+}
+
+// Cached text of every note in every project, only re-read when a file changes
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SearchIndex {
+    #[serde(default)]
+    pub files: BTreeMap<String, IndexedFile>, // File path relative to the base folder to its text
+}
+
+// Part of a file around one match
+#[derive(Debug, Clone)]
+pub struct Snippet {
+    pub before: String, // Text leading up to the match
+    pub matched: String, // Matched text as written in the file
+    pub after: String, // Text following the match
+}
+
+// Project whose notes contain every search word between them
+#[derive(Debug, Clone)]
+pub struct SearchHit {
+    pub project: String, // Project folder relative to the base folder
+    pub files: Vec<(String, Vec<Snippet>)>, // Files holding any of the words (relative to the project) with their snippets
+}
+
+// Purpose: Recursively collects the text files of a project, skipping hidden folders such as .git and projects inside it
+fn walk(dir: &Path, files: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for path in entries.filter_map(|e| e.ok().map(|e| e.path())) {
+        let hidden = path.file_name().is_some_and(|n| n.to_string_lossy().starts_with('.'));
+        if hidden {
+            continue;
+        }
+        if path.is_dir() {
+            if !path.join(META_FILE).exists() {
+                walk(&path, files); // Projects inside (series episodes) are indexed on their own
+            }
+        } else if path.extension().is_some_and(|e| TEXT_EXTENSIONS.contains(&e.to_string_lossy().to_lowercase().as_str())) {
+            files.push(path);
+        }
+    }
+}
+
+impl SearchIndex {
+    // Purpose: Reads the search index of a base folder, empty if there is none yet
+    pub fn load(base_dir: &Path) -> io::Result<Self> {
+        match fs::read_to_string(metadata::app_dir(base_dir).join(INDEX_FILE)) {
+            Ok(contents) => Ok(serde_json::from_str(&contents).unwrap_or_default()), // A damaged index is simply rebuilt
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(SearchIndex::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    // Purpose: Writes the search index of a base folder
+    pub fn save(&self, base_dir: &Path) -> io::Result<()> {
+        fs::create_dir_all(metadata::app_dir(base_dir))?;
+        fs::write(metadata::app_dir(base_dir).join(INDEX_FILE), serde_json::to_string(self)?)
+    }
+
+    /*
+    Purpose: Brings the index up to date, only reading files that are new or changed since the last refresh
+    Args: base_dir (Path) - base folder projects are created in
+    Return: Number of files read and number of files dropped from the index
+    */
+    pub fn refresh(&mut self, base_dir: &Path) -> (usize, usize) {
+        let mut files = BTreeMap::new();
+        let mut read = 0;
+
+        for (project, meta) in metadata::list_projects(base_dir) {
+            let mut paths = Vec::new();
+            walk(&project, &mut paths);
+
+            for path in paths {
+                let Ok(info) = fs::metadata(&path) else {
+                    continue;
+                };
+                if info.len() > MAX_FILE_SIZE {
+                    continue;
+                }
+                let modified = info.modified().ok().and_then(|t| t.duration_since(UNIX_EPOCH).ok()).map(|d| d.as_secs()).unwrap_or(0);
+                let key = path.strip_prefix(base_dir).unwrap_or(&path).to_string_lossy().replace('\\', "/");
+
+                // Unchanged files keep the text read last time
+                let file = match self.files.remove(&key) {
+                    Some(file) if file.modified == modified && file.size == info.len() => file,
+                    _ => {
+                        let Ok(bytes) = fs::read(&path) else {
+                            continue;
+                        };
+                        read += 1;
+                        IndexedFile { project: meta.relative_path(), modified, size: info.len(), text: String::from_utf8_lossy(&bytes).to_string() }
+                    }
+                };
+                files.insert(key, file);
+            }
+        }
+
+        let removed = self.files.len();
+        self.files = files;
+        (read, removed)
+    }
+
+    /*
+    Purpose: Finds the projects whose notes contain every word of a search, in one file or spread over several (Not case sensitive)
+    Args: query (String) - words to search for, "quoted words" are searched as one phrase
+    Return: Matching projects in path order, with snippets around the matches
+    */
+    pub fn search(&self, query: &str) -> Vec<SearchHit> {
+        let terms = query_terms(query);
+        if terms.is_empty() {
+            return Vec::new();
+        }
+
+        // Terms may be spread over several notes of a project, so files are grouped by project first
+        let mut projects: BTreeMap<&str, Vec<(&str, &IndexedFile, String)>> = BTreeMap::new();
+        for (key, file) in &self.files {
+            projects.entry(file.project.as_str()).or_default().push((key, file, fold(&file.text)));
+        }
+
+        let mut hits = Vec::new();
+        for (project, files) in projects {
+            if !terms.iter().all(|t| files.iter().any(|(_, _, lower)| lower.contains(t.as_str()))) {
+                continue;
+            }
+
+            // Every file holding one of the terms is listed with the snippets of the terms it has
+            let files = files
+                .into_iter()
+                .filter(|(_, _, lower)| terms.iter().any(|t| lower.contains(t.as_str())))
+                .map(|(key, file, _)| {
+                    let name = key.strip_prefix(&format!("{}/", project)).unwrap_or(key).to_string();
+                    (name, snippets(&file.text, &terms))
+                })
+                .collect();
+            hits.push(SearchHit { project: project.to_string(), files });
+        }
+        hits
+    }
+}
+
+// Purpose: Lower cases text one character at a time, the same way for searches and file contents
+fn fold(text: &str) -> String {
+    text.chars().flat_map(char::to_lowercase).collect()
+}
+
+// Purpose: Splits a search into lower case words, keeping "quoted phrases" together
+fn query_terms(query: &str) -> Vec<String> {
+    query
+        .split('"')
+        .enumerate()
+        .flat_map(|(i, part)| if i % 2 == 1 { vec![part.trim()] } else { part.split_whitespace().collect() })
+        .filter(|t| !t.is_empty())
+        .map(fold)
+        .collect()
+}
+
+/*
+Purpose: Cuts the text around the first few matches, every term gets at least one snippet
+Args: text (String) - file contents
+      terms (String list) - lower case search terms
+Return: Snippets in text order with the match split out for highlighting
+*/
+fn snippets(text: &str, terms: &[String]) -> Vec<Snippet> {
+    // Lower case letters can be longer than the original (İ gives i and a dot), so each one remembers the character it came from
+    let chars: Vec<char> = text.chars().collect();
+    let (lower, origin): (Vec<char>, Vec<usize>) = chars.iter().enumerate().flat_map(|(i, c)| c.to_lowercase().map(move |l| (l, i))).unzip();
+
+    // Matches as character ranges of the original text, per term
+    let matches: Vec<Vec<(usize, usize)>> = terms
+        .iter()
+        .map(|term| {
+            let term: Vec<char> = term.chars().collect();
+            let mut found = Vec::new();
+            let mut i = 0;
+            while i + term.len() <= lower.len() {
+                if lower[i..i + term.len()] == term[..] {
+                    found.push((origin[i], origin[i + term.len() - 1] + 1));
+                    i += term.len();
+                } else {
+                    i += 1;
+                }
+            }
+            found
+        })
+        .collect();
+
+    // The first match of each term comes first, the rest fill up to MAX_SNIPPETS
+    let limit = MAX_SNIPPETS.max(terms.len());
+    let mut picked: Vec<(usize, usize)> = Vec::new();
+    for (start, end) in matches.iter().filter_map(|m| m.first().copied()).chain(matches.iter().flat_map(|m| m.iter().skip(1).copied())) {
+        if picked.len() < limit && !picked.iter().any(|(s, e)| start < *e && *s < end) {
+            picked.push((start, end));
+        }
+    }
+    picked.sort();
+
+    let flat = |part: &[char]| part.iter().collect::<String>().replace(['\n', '\r', '\t'], " ");
+    picked
+        .into_iter()
+        .map(|(i, j)| {
+            let start = i.saturating_sub(SNIPPET_CHARS);
+            let end = (j + SNIPPET_CHARS).min(chars.len());
+            Snippet {
+                before: format!("{}{}", if start > 0 { "…" } else { "" }, flat(&chars[start..i]).trim_start()),
+                matched: flat(&chars[i..j]),
+                after: format!("{}{}", flat(&chars[j..end]).trim_end(), if end < chars.len() { "…" } else { "" }),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shows_snippets_for_every_term() {
+        let text = format!("drone {} sponsor", "x".repeat(200));
+        let found = snippets(&text, &query_terms("drone sponsor"));
+
+        assert_eq!(found.iter().map(|s| s.matched.as_str()).collect::<Vec<_>>(), vec!["drone", "sponsor"]);
+    }
+
+    #[test]
+    fn matches_letters_that_lower_case_to_several_characters() {
+        let found = snippets("Trip to İzmir", &query_terms("İZMIR"));
+
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].matched, "İzmir");
+        assert_eq!(found[0].before, "Trip to ");
+    }
+
+    #[test]
+    fn matches_terms_spread_over_a_projects_files() {
+        let file = |project: &str, text: &str| IndexedFile { project: project.to_string(), modified: 0, size: 0, text: text.to_string() };
+        let mut index = SearchIndex::default();
+        index.files.insert("2024-05-01_Drone/Ideas.md".to_string(), file("2024-05-01_Drone", "Film the drone at sunrise"));
+        index.files.insert("2024-05-01_Drone/Script/Script.txt".to_string(), file("2024-05-01_Drone", "Thank the sponsor"));
+        index.files.insert("2024-05-01_Drone/Notes.txt".to_string(), file("2024-05-01_Drone", "Nothing to see"));
+        index.files.insert("2024-06-01_Other/Ideas.md".to_string(), file("2024-06-01_Other", "Only a drone here"));
+
+        let hits = index.search("drone sponsor");
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].project, "2024-05-01_Drone");
+        let files: Vec<_> = hits[0].files.iter().map(|(name, snippets)| (name.as_str(), snippets[0].matched.as_str())).collect();
+        assert_eq!(files, vec![("Ideas.md", "drone"), ("Script/Script.txt", "sponsor")]);
+    }
+}
@@ -0,0 +1,48 @@
+use std::fs; // OS crate
+use std::path::Path; // File path crate
+use serde::{Deserialize, Serialize}; // Stored in the episode metadata
+
+pub const MUSIC_FOLDER: &str = "Music"; // Music shared by every episode, checked for licenses in each of them
+pub const SERIES_SUBFOLDERS: [&str; 4] = ["Intro", "Outro", "Branding", MUSIC_FOLDER]; // Assets shared by every episode of a series
+
+// Where an episode sits in its series
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SeriesEpisode {
+    pub series: String, // Folder name of the series inside the base folder
+    pub episode: u32, // Episode number, shown as E01, E02, ...
+}
+
+impl SeriesEpisode {
+    // Purpose: Gets the episode label such as "E01"
+    pub fn label(&self) -> String {
+        format!("E{:02}", self.episode)
+    }
+}
+
+// Purpose: Reads the episode number from a folder name containing a part such as "E07" ("2026-01-31_E07 Name")
+pub fn episode_number(folder_name: &str) -> Option<u32> {
+    folder_name
+        .split(['_', ' '])
+        .filter_map(|part| part.strip_prefix(['E', 'e']))
+        .find(|digits| !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit()))
+        .and_then(|digits| digits.parse().ok())
+}
+
+/*
+Purpose: Finds the next episode number of a series from the episode folders already in it
+Args: series_dir (Path) - series folder
+Return: One more than the highest episode number found, 1 if there are none
+*/
+pub fn next_episode(series_dir: &Path) -> u32 {
+    fs::read_dir(series_dir)
+        .map(|entries| {
+            entries
+                .filter_map(|e| e.ok())
+                .filter(|e| e.path().is_dir())
+                .filter_map(|e| episode_number(&e.file_name().to_string_lossy()))
+                .max()
+                .unwrap_or(0)
+        })
+        .unwrap_or(0)
+        + 1
+}
@@ -0,0 +1,142 @@
+use std::{fs, io}; // OS crate
+use std::path::{Path, PathBuf}; // File path crate
+use chrono::Local; // Submission time stamp
+use zip::write::SimpleFileOptions; // Compression settings for each zipped file
+
+use crate::config::Config;
+use crate::metadata::ProjectMeta;
+use crate::school::sanitize;
+
+/*
+Purpose: Checks a file or folder name against a pattern where * matches any text and ? one character
+Args: pattern (String) - pattern such as "*.tmp" (Not case sensitive)
+      name (String) - file or folder name
+Return: True if the name matches
+*/
+pub fn wildcard_match(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.to_lowercase().chars().collect();
+    let name: Vec<char> = name.to_lowercase().chars().collect();
+    let (mut p, mut n) = (0, 0);
+    let mut star: Option<(usize, usize)> = None; // Last * seen and the name position it is matched up to
+
+    while n < name.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == name[n]) {
+            p += 1;
+            n += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            star = Some((p, n));
+            p += 1;
+        } else if let Some((star_p, star_n)) = star {
+            // Lets the last * swallow one more character and tries again
+            p = star_p + 1;
+            n = star_n + 1;
+            star = Some((star_p, star_n + 1));
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+/*
+Purpose: Fills in a submission zip name pattern
+Args: pattern (String) - pattern using {student}, {course} and {assignment}
+      student (String) - student name from the settings
+      course (String) - course code
+      assignment (String) - assignment title
+Return: Zip file name with unsafe characters replaced
+*/
+pub fn zip_name(pattern: &str, student: &str, course: &str, assignment: &str) -> String {
+    let name = pattern
+        .replace("{student}", student.trim())
+        .replace("{course}", course)
+        .replace("{assignment}", assignment.trim())
+        .replace(' ', "_");
+    format!("{}.zip", sanitize(&name))
+}
+
+// Purpose: Recursively adds a folder to the zip, skipping excluded names
+fn add_folder<W: io::Write + io::Seek>(zip: &mut zip::ZipWriter<W>, root: &Path, dir: &Path, excludes: &[String]) -> io::Result<usize> {
+    let options = SimpleFileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+    let mut entries: Vec<PathBuf> = fs::read_dir(dir)?.filter_map(|e| e.ok().map(|e| e.path())).collect();
+    entries.sort();
+
+    let mut added = 0;
+    for path in entries {
+        let name = path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+        if excludes.iter().any(|pattern| wildcard_match(pattern, &name)) {
+            continue;
+        }
+
+        let relative = path.strip_prefix(root).unwrap_or(&path).to_string_lossy().replace('\\', "/");
+        if path.is_dir() {
+            zip.add_directory(relative, options)?;
+            added += add_folder(zip, root, &path, excludes)?;
+        } else {
+            zip.start_file(relative, options)?;
+            io::copy(&mut fs::File::open(&path)?, zip)?;
+            added += 1;
+        }
+    }
+    Ok(added)
+}
+
+// Purpose: Writes a folder into a new zip file, returning the number of files added
+fn write_zip(target: &Path, source: &Path, excludes: &[String]) -> io::Result<usize> {
+    let mut zip = zip::ZipWriter::new(fs::File::create(target)?);
+    let added = add_folder(&mut zip, source, source, excludes)?;
+    zip.finish()?;
+    Ok(added)
+}
+
+/*
+Purpose: Zips an assignment folder for submission and records the submission time
+Args: semester (Path) - semester project folder
+      folder (String) - assignment folder relative to the semester, as stored in its metadata
+      config (Config) - student name, default zip pattern and excluded names
+Return: Path to the zip, written next to the assignment folder
+*/
+pub fn bundle(semester: &Path, folder: &str, config: &Config) -> io::Result<PathBuf> {
+    if config.student_name.trim().is_empty() {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, "Set your student name in Settings first"));
+    }
+
+    let mut meta = ProjectMeta::load(semester)?;
+    let info = meta.school.as_mut().ok_or(io::Error::new(io::ErrorKind::NotFound, "Project is not a semester"))?;
+    let assignment = info
+        .assignments
+        .iter_mut()
+        .find(|a| a.folder == folder)
+        .ok_or(io::Error::new(io::ErrorKind::NotFound, format!("No assignment in '{}'", folder)))?;
+
+    // Courses can use their own naming convention
+    let pattern = info
+        .courses
+        .iter()
+        .find(|c| c.code == assignment.course)
+        .and_then(|c| c.submission_pattern.clone())
+        .unwrap_or(config.submission_pattern.clone());
+
+    let source = semester.join(folder);
+    let target = source
+        .parent()
+        .unwrap_or(semester)
+        .join(zip_name(&pattern, &config.student_name, &assignment.course, &assignment.title));
+
+    // A zip that failed part way or holds nothing is removed so no broken submission is left behind
+    let written = match write_zip(&target, &source, &config.submission_excludes) {
+        Ok(0) => Err(io::Error::new(io::ErrorKind::InvalidInput, "Assignment folder has no files to submit")),
+        other => other,
+    };
+    if let Err(e) = written {
+        let _ = fs::remove_file(&target);
+        return Err(e);
+    }
+
+    assignment.submitted = Some(Local::now());
+    assignment.submission = Some(target.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default());
+    meta.save(semester)?;
+
+    Ok(target)
+}
@@ -0,0 +1,56 @@
+use std::io; // OS crate
+use std::collections::BTreeSet; // Sorted tag lists without repeats
+use std::path::Path; // File path crate
+
+use crate::metadata::ProjectMeta;
+
+// Purpose: Splits comma separated tags into trimmed lower case tags without repeats
+pub fn parse_tags(text: &str) -> Vec<String> {
+    let tags: BTreeSet<String> = text.split(',').map(|t| t.trim().to_lowercase()).filter(|t| !t.is_empty()).collect();
+    tags.into_iter().collect()
+}
+
+/*
+Purpose: Replaces the tags of a project
+Args: project (Path) - project folder
+      tags (String list) - new tags, cleaned up by parse_tags
+Return: Error message if failed
+*/
+pub fn set_tags(project: &Path, tags: &[String]) -> io::Result<()> {
+    let mut meta = ProjectMeta::load(project)?;
+    meta.tags = parse_tags(&tags.join(","));
+    meta.save(project)
+}
+
+// Purpose: Gets every tag used by any of the projects, sorted
+pub fn all_tags<'a>(metas: impl Iterator<Item = &'a ProjectMeta>) -> Vec<String> {
+    let tags: BTreeSet<&String> = metas.flat_map(|meta| meta.tags.iter()).collect();
+    tags.into_iter().cloned().collect()
+}
+
+// Tag combination projects are filtered by, such as "sponsor collab|tutorial -cs101"
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TagFilter {
+    required: Vec<Vec<String>>, // Every group needs at least one of its tags ("collab|tutorial" is one group)
+    excluded: Vec<String>, // Tags a project must not have (written with a leading -)
+}
+
+impl TagFilter {
+    // Purpose: Reads a filter where spaces mean "and", | means "or" and a leading - means "not"
+    pub fn parse(text: &str) -> Self {
+        let mut filter = TagFilter::default();
+        for word in text.split_whitespace() {
+            match word.strip_prefix('-') {
+                Some(tag) if !tag.is_empty() => filter.excluded.push(tag.to_lowercase()),
+                Some(_) => {}
+                None => filter.required.push(word.split('|').filter(|t| !t.is_empty()).map(str::to_lowercase).collect()),
+            }
+        }
+        filter
+    }
+
+    // Purpose: Checks whether a project's tags pass the filter (an empty filter passes everything)
+    pub fn matches(&self, tags: &[String]) -> bool {
+        self.required.iter().all(|group| group.iter().any(|t| tags.contains(t))) && !self.excluded.iter().any(|t| tags.contains(t))
+    }
+}
@@ -0,0 +1,182 @@
+use std::{fs, io}; // OS crate
+use std::path::{Path, PathBuf}; // File path crate
+use serde_json::{Value, json}; // OpenTimelineIO files are JSON
+
+use crate::config::Config;
+use crate::media::{self, Clip};
+use crate::metadata::ProjectMeta;
+use crate::mlt::VideoProfile;
+use crate::premiere::xml_escape;
+
+/*
+Purpose: Turns a file path into a file:// URI, percent-encoding anything unsafe
+Args: path (Path) - file path (made absolute first)
+Return: URI such as "file:///C:/Videos/My%20clip.mp4"
+*/
+pub fn file_uri(path: &Path) -> String {
+    let absolute = std::path::absolute(path).unwrap_or(path.to_path_buf());
+    let text = absolute.to_string_lossy().replace('\\', "/");
+    let mut uri = String::from(if text.starts_with('/') { "file://" } else { "file:///" });
+
+    for byte in text.bytes() {
+        if byte.is_ascii_alphanumeric() || b"/-_.~:".contains(&byte) {
+            uri.push(byte as char);
+        } else {
+            uri.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    uri
+}
+
+// Purpose: Converts a length in seconds into whole frames
+fn frames(seconds: f64, profile: &VideoProfile) -> u64 {
+    (seconds * profile.fps()).round() as u64
+}
+
+// Purpose: Writes a number of frames as an FCPXML rational time such as "1001/30000s"
+fn fcp_time(frames: u64, profile: &VideoProfile) -> String {
+    format!("{}/{}s", frames * profile.frame_rate_den as u64, profile.frame_rate_num)
+}
+
+/*
+Purpose: Builds an FCPXML 1.9 project with the clips one after another on the main storyline
+Args: name (String) - project name
+      clips (Clip list) - clips in timeline order, each with a duration
+      profile (VideoProfile) - sequence resolution and frame rate
+Return: FCPXML document
+*/
+fn fcpxml(name: &str, clips: &[(Clip, u64)], profile: &VideoProfile) -> String {
+    let mut resources = format!(
+        "    <format id=\"r1\" frameDuration=\"{}\" width=\"{}\" height=\"{}\"/>\n",
+        fcp_time(1, profile), profile.width, profile.height
+    );
+    let mut spine = String::new();
+    let mut offset = 0;
+
+    for (i, (clip, length)) in clips.iter().enumerate() {
+        let id = format!("r{}", i + 2);
+        let clip_name = xml_escape(&clip.name());
+        resources.push_str(&format!(
+            "    <asset id=\"{}\" name=\"{}\" start=\"0s\" duration=\"{}\" hasVideo=\"1\" hasAudio=\"{}\" format=\"r1\">\n      <media-rep kind=\"original-media\" src=\"{}\"/>\n    </asset>\n",
+            id,
+            clip_name,
+            fcp_time(*length, profile),
+            u8::from(clip.has_audio),
+            xml_escape(&file_uri(&clip.path))
+        ));
+        spine.push_str(&format!(
+            "            <asset-clip ref=\"{}\" name=\"{}\" offset=\"{}\" start=\"0s\" duration=\"{}\" format=\"r1\" tcFormat=\"NDF\"/>\n",
+            id, clip_name, fcp_time(offset, profile), fcp_time(*length, profile)
+        ));
+        offset += length;
+    }
+
+    let name = xml_escape(name);
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<!DOCTYPE fcpxml>\n<fcpxml version=\"1.9\">\n  <resources>\n{}  </resources>\n  <library>\n    <event name=\"{}\">\n      <project name=\"{}\">\n        <sequence format=\"r1\" duration=\"{}\" tcStart=\"0s\" tcFormat=\"NDF\">\n          <spine>\n{}          </spine>\n        </sequence>\n      </project>\n    </event>\n  </library>\n</fcpxml>\n",
+        resources, name, name, fcp_time(offset, profile), spine
+    )
+}
+
+// Purpose: Builds an OpenTimelineIO time range starting at a frame
+fn time_range(start: u64, length: u64, fps: f64) -> Value {
+    json!({
+        "OTIO_SCHEMA": "TimeRange.1",
+        "start_time": { "OTIO_SCHEMA": "RationalTime.1", "rate": fps, "value": start },
+        "duration": { "OTIO_SCHEMA": "RationalTime.1", "rate": fps, "value": length }
+    })
+}
+
+/*
+Purpose: Builds an OpenTimelineIO timeline with the clips one after another on an A-roll video track
+Args: name (String) - timeline name
+      clips (Clip list) - clips in timeline order, each with its length in frames
+      profile (VideoProfile) - frame rate of the timeline
+Return: OTIO JSON
+*/
+fn otio(name: &str, clips: &[(Clip, u64)], profile: &VideoProfile) -> Value {
+    let fps = profile.fps();
+    let children: Vec<Value> = clips
+        .iter()
+        .map(|(clip, length)| {
+            json!({
+                "OTIO_SCHEMA": "Clip.2",
+                "name": clip.name(),
+                "metadata": {},
+                "source_range": time_range(0, *length, fps),
+                "effects": [],
+                "markers": [],
+                "enabled": true,
+                "media_references": {
+                    "DEFAULT_MEDIA": {
+                        "OTIO_SCHEMA": "ExternalReference.1",
+                        "name": clip.name(),
+                        "metadata": {},
+                        "available_range": time_range(0, *length, fps),
+                        "target_url": file_uri(&clip.path)
+                    }
+                },
+                "active_media_reference_key": "DEFAULT_MEDIA"
+            })
+        })
+        .collect();
+
+    json!({
+        "OTIO_SCHEMA": "Timeline.1",
+        "name": name,
+        "metadata": { "width": profile.width, "height": profile.height },
+        "global_start_time": null,
+        "tracks": {
+            "OTIO_SCHEMA": "Stack.1",
+            "name": "tracks",
+            "metadata": {},
+            "source_range": null,
+            "effects": [],
+            "markers": [],
+            "enabled": true,
+            "children": [{
+                "OTIO_SCHEMA": "Track.1",
+                "name": "A-roll",
+                "kind": "Video",
+                "metadata": {},
+                "source_range": null,
+                "effects": [],
+                "markers": [],
+                "enabled": true,
+                "children": children
+            }]
+        }
+    })
+}
+
+/*
+Purpose: Writes FCPXML and OpenTimelineIO timelines listing a project's A-roll clips in recording order
+Args: project (Path) - project folder with an A-roll and a Save folder
+      config (Config) - settings holding the project type's sequence settings
+Return: Written timeline files and a warning for every clip that was left out
+*/
+pub fn export(project: &Path, config: &Config) -> io::Result<(Vec<PathBuf>, Vec<String>)> {
+    let meta = ProjectMeta::load(project)?;
+    let profile = config.video_profile(meta.project_type);
+    let (clips, mut warnings) = media::list_clips(&project.join("A-roll"));
+
+    let mut timed = Vec::new();
+    for clip in clips {
+        match clip.duration.map(|d| frames(d, &profile)) {
+            Some(length) if length > 0 => timed.push((clip, length)),
+            _ => warnings.push(format!("{}: no duration found, left out", clip.name())),
+        }
+    }
+    if timed.is_empty() {
+        return Err(io::Error::new(io::ErrorKind::NotFound, "No readable MP4/MOV clips in A-roll"));
+    }
+
+    let save = project.join("Save");
+    fs::create_dir_all(&save)?;
+    let fcpxml_path = save.join(format!("{}_timeline.fcpxml", meta.name));
+    let otio_path = save.join(format!("{}_timeline.otio", meta.name));
+    fs::write(&fcpxml_path, fcpxml(&meta.name, &timed, &profile))?;
+    fs::write(&otio_path, serde_json::to_string_pretty(&otio(&meta.name, &timed, &profile))?)?;
+
+    Ok((vec![fcpxml_path, otio_path], warnings))
+}
@@ -7,15 +7,18 @@ use std::sync::{Arc, Mutex}; // Multitasking crate
 use chrono::Local; // Gets local time information from computer
 use eframe::egui; // Allows for GUI interface
 
+#[derive(Default)]
 enum ScanStatus {
+    #[default]
     Idle,
     Scanning,
     Found(PathBuf),
     NotFound,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
 enum ProjectType {
+    #[default]
     None,
     Youtube,
     School,
@@ -34,12 +37,10 @@ fn get_base_dir(start: &Path, target_path: &str) -> Option<PathBuf> {
         let entry = entry.ok()?;
         let path = entry.path();
 
-        if path.is_dir() {
-            if let Some(name) = path.file_name() {
-                // If the selected folder is target_path (Not case sensitive) it is chosen as the main base folder
-                if name.to_string_lossy().eq_ignore_ascii_case(target_path) {
-                    return Some(path);
-                }
+        if path.is_dir() && let Some(name) = path.file_name() {
+            // If the selected folder is target_path (Not case sensitive) it is chosen as the main base folder
+            if name.to_string_lossy().eq_ignore_ascii_case(target_path) {
+                return Some(path);
             }
         }
     }
@@ -69,7 +70,7 @@ fn create_directory(base_dir: &Path, folder_name: &str, subfolders: &[&str]) ->
     if main.join("Save").exists(){
         let textfile_name = "ideas.txt";
         let contents = "Video ideas:\n\nThumbnail ideas:\n\n";
-        fs::write(&main.join(textfile_name), contents)?; // Creates a .txt file to plan ideas
+        fs::write(main.join(textfile_name), contents)?; // Creates a .txt file to plan ideas
 
         // Creates a premiere save file
         let prproj_template = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("Assets").join("template.prproj");
@@ -102,18 +103,6 @@ struct MyApp {
     pending_create: bool, // Indicator for if the base_path 
 }
 
-impl Default for ScanStatus {
-    fn default() -> Self {
-        ScanStatus::Idle
-    }
-}
-
-impl Default for ProjectType {
-    fn default() -> Self {
-        ProjectType::None
-    }
-}
-
 impl MyApp {
     // Purpose: Scans for base folder directory
     fn start_scan(&mut self) { 
@@ -166,7 +155,7 @@ impl MyApp {
         let final_name: String = if matches!(self.project_type, ProjectType::Youtube) {
             format!("{}_{}", date, self.folder_name.trim())            
         } else {
-            format!("{}", self.folder_name.trim())
+            self.folder_name.trim().to_string()
         };
 
         match create_directory(&base_dir, &final_name, subfolders) {
@@ -316,7 +305,7 @@ impl eframe::App for MyApp {
             ui.vertical_centered(|ui|{
                 match &self.base_path {
                     Some(path) => {
-                        ui.label(format!("Base path: {}", path.to_string_lossy().to_string()));
+                        ui.label(format!("Base path: {}", path.to_string_lossy()));
                         
                     } None => {
                         ui.label("No base path selected");
@@ -326,7 +315,7 @@ impl eframe::App for MyApp {
 
             if !self.project_path.to_string_lossy().to_string().is_empty() {
                ui.vertical_centered(|ui|{
-                    ui.label(format!("Project path: {}", self.project_path.to_string_lossy().to_string()));
+                    ui.label(format!("Project path: {}", self.project_path.to_string_lossy()));
                     ctx.copy_text(self.project_path.to_string_lossy().to_string());
                });
             }
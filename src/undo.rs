@@ -0,0 +1,135 @@
+use std::{fs, io}; // OS crate
+use std::io::Read; // Streams file contents into the hash
+use std::path::{Path, PathBuf}; // File path crate
+use std::time::UNIX_EPOCH; // Converts modified times into plain numbers
+use chrono::Local; // Time stamp added to trashed folder names
+use serde::{Deserialize, Serialize}; // Records are stored in the project metadata
+
+use crate::metadata::{self, ProjectMeta, META_FILE};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FileRecord {
+    pub path: String, // Path relative to the project folder using '/' separators
+    pub is_dir: bool, // Folders are recorded so added or removed folders are noticed
+    pub size: u64, // File size in bytes (0 for folders)
+    pub modified: u64, // Last modified time in seconds since 1970
+    pub hash: String, // FNV-1a hash of the file contents (empty for folders)
+}
+
+/*
+Purpose: Hashes a file with 64 bit FNV-1a so content changes can be detected
+Args: path (Path) - file to hash
+Return: Hash as a hex string
+*/
+fn hash_file(path: &Path) -> io::Result<String> {
+    let mut file = fs::File::open(path)?;
+    let mut buffer = [0u8; 8192];
+    let mut hash: u64 = 0xcbf29ce484222325;
+
+    loop {
+        let read = file.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        for byte in &buffer[..read] {
+            hash ^= *byte as u64;
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+    }
+
+    Ok(format!("{:016x}", hash))
+}
+
+// Purpose: Recursively adds every entry under dir to records
+fn walk(root: &Path, dir: &Path, records: &mut Vec<FileRecord>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let relative = path.strip_prefix(root).unwrap_or(&path).to_string_lossy().replace('\\', "/");
+
+        // The metadata file changes on its own, so it is never part of the snapshot
+        if relative == META_FILE {
+            continue;
+        }
+
+        let info = fs::metadata(&path)?;
+        let modified = info.modified()?.duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+
+        if info.is_dir() {
+            records.push(FileRecord { path: relative, is_dir: true, size: 0, modified, hash: String::new() });
+            walk(root, &path, records)?;
+        } else {
+            records.push(FileRecord { path: relative, is_dir: false, size: info.len(), modified, hash: hash_file(&path)? });
+        }
+    }
+    Ok(())
+}
+
+/*
+Purpose: Records every file and folder inside a project so later changes can be found
+Args: project (Path) - project folder to record
+Return: File records sorted by path
+*/
+pub fn snapshot(project: &Path) -> io::Result<Vec<FileRecord>> {
+    let mut records = Vec::new();
+    walk(project, project, &mut records)?;
+    records.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(records)
+}
+
+/*
+Purpose: Compares a project with the snapshot taken when it was created
+Args: project (Path) - project folder to check
+      recorded (FileRecord list) - snapshot stored in the project metadata
+Return: Paths that were added, removed or changed (empty if untouched)
+*/
+pub fn changed_entries(project: &Path, recorded: &[FileRecord]) -> io::Result<Vec<String>> {
+    let current = snapshot(project)?;
+    let mut changed = Vec::new();
+
+    for record in &current {
+        match recorded.iter().find(|r| r.path == record.path) {
+            // Folder times change whenever something inside changes, so only files compare times
+            Some(old) if old.is_dir && record.is_dir => {}
+            Some(old) if old == record => {}
+            // Same contents with a new time stamp (e.g. file was re-saved) still counts as untouched
+            Some(old) if !old.is_dir && old.size == record.size && old.hash == record.hash => {}
+            _ => changed.push(record.path.clone()),
+        }
+    }
+
+    for record in recorded {
+        if !current.iter().any(|r| r.path == record.path) {
+            changed.push(record.path.clone());
+        }
+    }
+
+    Ok(changed)
+}
+
+/*
+Purpose: Removes a freshly created project by moving it into the tool's trash folder
+Args: base_dir (Path) - base folder the project was created in
+      project (Path) - project folder to remove
+Return: Path of the project inside the trash, or an error if it was modified since creation
+*/
+pub fn undo_create(base_dir: &Path, project: &Path) -> io::Result<PathBuf> {
+    let meta = ProjectMeta::load(project)?;
+    let changed = changed_entries(project, &meta.files)?;
+
+    if !changed.is_empty() {
+        return Err(io::Error::other(format!(
+            "{} item(s) changed since creation (e.g. {})",
+            changed.len(),
+            changed[0]
+        )));
+    }
+
+    let trash = metadata::app_dir(base_dir).join("trash");
+    fs::create_dir_all(&trash)?;
+
+    let name = project.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+    let target = trash.join(format!("{}_{}", name, Local::now().format("%Y%m%d-%H%M%S")));
+    fs::rename(project, &target)?;
+
+    Ok(target)
+}
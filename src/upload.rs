@@ -0,0 +1,346 @@
+use std::{fs, io}; // OS crate
+use std::path::{Path, PathBuf}; // File path crate
+use serde::Serialize; // upload.json
+
+use crate::license;
+use crate::metadata::{self, ProjectMeta};
+
+const UPLOAD_HEADING: &str = "upload"; // Ideas heading the upload fields are read from
+const TITLE_LIMIT: usize = 100; // Longest title YouTube accepts
+const TITLE_VISIBLE: usize = 70; // Titles longer than this are cut off in search results
+const DESCRIPTION_LIMIT: usize = 5000; // Longest description YouTube accepts
+const TAGS_LIMIT: usize = 500; // Most tag characters YouTube accepts, counted as in tag_characters
+const MIN_CHAPTERS: usize = 3; // YouTube ignores chapter lists shorter than this
+const MIN_CHAPTER_SECONDS: u32 = 10; // Shortest chapter YouTube accepts
+
+// One chapter of the video
+#[derive(Debug, Clone, Serialize)]
+pub struct Chapter {
+    pub seconds: u32, // Start of the chapter
+    pub title: String, // Chapter name
+}
+
+impl Chapter {
+    // Purpose: Gets the start time the way YouTube shows it (m:ss or h:mm:ss)
+    pub fn timestamp(&self) -> String {
+        let (h, m, s) = (self.seconds / 3600, self.seconds / 60 % 60, self.seconds % 60);
+        if h > 0 { format!("{}:{:02}:{:02}", h, m, s) } else { format!("{}:{:02}", m, s) }
+    }
+}
+
+// Upload fields read from the Upload section of an ideas file
+#[derive(Debug, Clone, Default)]
+pub struct UploadInfo {
+    pub titles: Vec<String>, // Title options, the first one is used
+    pub description: String, // Description text without chapters and links
+    pub tags: Vec<String>, // Tags without the # sign
+    pub chapters: Vec<Chapter>, // Chapters in the order they were written
+    pub links: Vec<String>, // Links listed under the description
+    pub credits: String, // Credits block from the project's license ledger, added last
+}
+
+// Purpose: Gets the text of a markdown heading line without #, ** and the trailing colon, with its level
+fn heading(line: &str) -> Option<(usize, String)> {
+    let level = line.chars().take_while(|c| *c == '#').count();
+    if level == 0 || !line[level..].starts_with(' ') {
+        return None;
+    }
+    let text = line[level..].trim().trim_matches('*').trim().trim_end_matches(':').trim_matches('*').trim();
+    Some((level, text.to_lowercase()))
+}
+
+// Purpose: Gets the text of a "* item" or "- item" line
+fn bullet(line: &str) -> Option<&str> {
+    let line = line.trim();
+    line.strip_prefix("* ").or(line.strip_prefix("- ")).map(str::trim).filter(|item| !item.is_empty())
+}
+
+// Purpose: Reads a timestamp such as "1:05" or "1:02:03" into seconds
+fn parse_timestamp(text: &str) -> Option<u32> {
+    let parts: Vec<u32> = text.split(':').map(|p| p.parse().ok()).collect::<Option<_>>()?;
+    match parts.as_slice() {
+        [m, s] if *s < 60 => Some(m * 60 + s),
+        [h, m, s] if *m < 60 && *s < 60 => Some(h * 3600 + m * 60 + s),
+        _ => None,
+    }
+}
+
+/*
+Purpose: Counts tag characters the way YouTube does, tags with spaces are quoted and tags are comma separated
+Args: tags (String list) - tags
+Return: Character count compared against the 500 character limit
+*/
+fn tag_characters(tags: &[String]) -> usize {
+    let quoted: usize = tags.iter().map(|t| t.chars().count() + if t.contains(' ') { 2 } else { 0 }).sum();
+    quoted + tags.len().saturating_sub(1)
+}
+
+impl UploadInfo {
+    /*
+    Purpose: Reads the Upload section of an ideas file
+    Args: markdown (String) - ideas file contents
+    Return: Upload fields, or None if the file has no Upload section
+    */
+    pub fn parse(markdown: &str) -> Option<Self> {
+        let mut info = UploadInfo::default();
+        let mut section_level = None; // Level of the Upload heading once it was found
+        let mut field = String::new();
+        let mut description = Vec::new();
+
+        for line in markdown.lines() {
+            if let Some((level, text)) = heading(line) {
+                match section_level {
+                    None if text == UPLOAD_HEADING => section_level = Some(level),
+                    Some(section) if level <= section => break,
+                    Some(_) => field = text,
+                    None => {}
+                }
+                continue;
+            }
+            if section_level.is_none() {
+                continue;
+            }
+
+            // Titles, tags, chapters and links are lists, the description is kept as written
+            match (field.as_str(), bullet(line)) {
+                ("titles" | "title" | "title options", Some(item)) => info.titles.push(item.to_string()),
+                ("tags", Some(item)) => info.tags.extend(item.split(',').map(|t| t.trim().trim_start_matches('#').to_string()).filter(|t| !t.is_empty())),
+                ("links", Some(item)) => info.links.push(item.to_string()),
+                ("chapters", Some(item)) => {
+                    let (time, title) = item.split_once(char::is_whitespace).unwrap_or((item, ""));
+                    if let Some(seconds) = parse_timestamp(time) {
+                        info.chapters.push(Chapter { seconds, title: title.trim().trim_start_matches(['-', '–']).trim().to_string() });
+                    }
+                }
+                ("description", _) => description.push(line.trim_end()),
+                _ => {}
+            }
+        }
+
+        section_level?;
+        info.description = description.join("\n").trim().to_string();
+        Some(info)
+    }
+
+    // Purpose: Builds the description to paste into YouTube, followed by the chapters, links and credits
+    pub fn full_description(&self) -> String {
+        let mut text = self.description.clone();
+        if !self.chapters.is_empty() {
+            text.push_str("\n\nChapters:\n");
+            let lines: Vec<String> = self.chapters.iter().map(|c| format!("{} {}", c.timestamp(), c.title)).collect();
+            text.push_str(&lines.join("\n"));
+        }
+        if !self.links.is_empty() {
+            text.push_str("\n\nLinks:\n");
+            text.push_str(&self.links.join("\n"));
+        }
+        if !self.credits.is_empty() {
+            text.push_str("\n\n");
+            text.push_str(&self.credits);
+        }
+        text.trim().to_string()
+    }
+
+    // Purpose: Checks the fields against YouTube's limits and chapter rules
+    pub fn warnings(&self) -> Vec<String> {
+        let mut warnings = Vec::new();
+
+        if self.titles.is_empty() {
+            warnings.push("No title options".to_string());
+        }
+        for title in &self.titles {
+            let length = title.chars().count();
+            if length > TITLE_LIMIT {
+                warnings.push(format!("Title '{}' is {} characters, the limit is {}", title, length, TITLE_LIMIT));
+            } else if length > TITLE_VISIBLE {
+                warnings.push(format!("Title '{}' is {} characters, search results cut it off after about {}", title, length, TITLE_VISIBLE));
+            }
+            if title.contains(['<', '>']) {
+                warnings.push(format!("Title '{}' contains < or >, which YouTube does not allow", title));
+            }
+        }
+
+        let description = self.full_description();
+        let length = description.chars().count();
+        if length > DESCRIPTION_LIMIT {
+            warnings.push(format!("Description is {} characters, the limit is {}", length, DESCRIPTION_LIMIT));
+        }
+        if description.contains(['<', '>']) {
+            warnings.push("Description contains < or >, which YouTube does not allow".to_string());
+        }
+
+        let tag_length = tag_characters(&self.tags);
+        if tag_length > TAGS_LIMIT {
+            warnings.push(format!("Tags use {} characters, the limit is {}", tag_length, TAGS_LIMIT));
+        }
+
+        // YouTube only shows chapters that start at 0:00, go forward and are long enough
+        if !self.chapters.is_empty() {
+            if self.chapters[0].seconds != 0 {
+                warnings.push("First chapter must start at 0:00".to_string());
+            }
+            if self.chapters.len() < MIN_CHAPTERS {
+                warnings.push(format!("YouTube needs at least {} chapters", MIN_CHAPTERS));
+            }
+            for pair in self.chapters.windows(2) {
+                if pair[1].seconds < pair[0].seconds + MIN_CHAPTER_SECONDS {
+                    warnings.push(format!(
+                        "Chapter '{}' at {} is shorter than {} seconds or out of order",
+                        pair[0].title,
+                        pair[0].timestamp(),
+                        MIN_CHAPTER_SECONDS
+                    ));
+                }
+            }
+        }
+
+        warnings
+    }
+
+    // Purpose: Builds the ready to paste upload.txt contents
+    fn text(&self) -> String {
+        let mut text = format!("TITLE\n{}\n", self.titles.first().cloned().unwrap_or_default());
+        if self.titles.len() > 1 {
+            text.push_str(&format!("\nOTHER TITLE OPTIONS\n{}\n", self.titles[1..].join("\n")));
+        }
+        text.push_str(&format!("\nDESCRIPTION\n{}\n", self.full_description()));
+        text.push_str(&format!("\nTAGS ({} of {} characters)\n{}\n", tag_characters(&self.tags), TAGS_LIMIT, self.tags.join(", ")));
+        text
+    }
+}
+
+// upload.json contents
+#[derive(Serialize)]
+struct UploadFile<'a> {
+    title: String, // First title option
+    titles: &'a [String], // Every title option
+    description: String, // Description with chapters and links
+    tags: &'a [String], // Tags without the # sign
+    chapters: &'a [Chapter], // Chapters with their start in seconds
+    links: &'a [String], // Links listed under the description
+    warnings: &'a [String], // Problems YouTube would reject or cut off
+}
+
+/*
+Purpose: Writes upload.txt and upload.json into a project from the Upload section of its ideas file
+Args: project (Path) - project folder containing an *_ideas.md file
+      licensed_folders (String list) - folders checked for music and stock footage without a license
+Return: Written files and the warnings found
+*/
+pub fn write_upload(project: &Path, licensed_folders: &[String]) -> io::Result<(Vec<PathBuf>, Vec<String>)> {
+    let ideas = metadata::ideas_file(project).ok_or(io::Error::new(io::ErrorKind::NotFound, "Project has no ideas file"))?;
+    let mut info = UploadInfo::parse(&fs::read_to_string(&ideas)?)
+        .ok_or(io::Error::new(io::ErrorKind::InvalidData, "Ideas file has no Upload section"))?;
+
+    // Music and stock footage are credited under the description, unlicensed assets are flagged
+    let meta = ProjectMeta::load(project).ok();
+    if let Some(meta) = &meta {
+        info.credits = license::attribution_block(&meta.licenses);
+    }
+    let mut warnings = info.warnings();
+    if let Some(meta) = &meta {
+        warnings.extend(license::warnings(project, meta, licensed_folders));
+    }
+
+    let text_path = project.join("upload.txt");
+    let json_path = project.join("upload.json");
+    let file = UploadFile {
+        title: info.titles.first().cloned().unwrap_or_default(),
+        titles: &info.titles,
+        description: info.full_description(),
+        tags: &info.tags,
+        chapters: &info.chapters,
+        links: &info.links,
+        warnings: &warnings,
+    };
+
+    let mut text = info.text();
+    if !warnings.is_empty() {
+        text.push_str(&format!("\nWARNINGS\n{}\n", warnings.join("\n")));
+    }
+    fs::write(&text_path, text)?;
+    fs::write(&json_path, serde_json::to_string_pretty(&file)?)?;
+
+    Ok((vec![text_path, json_path], warnings))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const IDEAS: &str = "# Video ideas
+* Not part of the upload
+
+## **Upload:**
+### Titles
+* I flew a drone over the lake
+- Lake drone flight
+### Description
+First line.
+
+Second paragraph.
+### Tags
+* drone, #lake,  , fpv
+* travel
+### Chapters
+* 0:00 Intro
+* 1:05 - Take off
+* 1:02:03 – Landing
+* later Not a time
+### Links
+* https://example.com/gear
+## Thumbnail ideas
+* Drone in the sky
+";
+
+    #[test]
+    fn reads_only_the_upload_section() {
+        let info = UploadInfo::parse(IDEAS).unwrap();
+
+        assert_eq!(info.titles, vec!["I flew a drone over the lake", "Lake drone flight"]);
+        assert_eq!(info.description, "First line.\n\nSecond paragraph.");
+        assert_eq!(info.tags, vec!["drone", "lake", "fpv", "travel"]);
+        assert_eq!(info.links, vec!["https://example.com/gear"]);
+        let chapters: Vec<(u32, &str)> = info.chapters.iter().map(|c| (c.seconds, c.title.as_str())).collect();
+        assert_eq!(chapters, vec![(0, "Intro"), (65, "Take off"), (3723, "Landing")]);
+    }
+
+    #[test]
+    fn needs_an_upload_heading() {
+        assert!(UploadInfo::parse("# Video ideas\n* Upload: soon\n").is_none());
+        assert!(UploadInfo::parse("#Upload\n").is_none());
+    }
+
+    #[test]
+    fn reads_and_writes_timestamps() {
+        assert_eq!(parse_timestamp("0:59"), Some(59));
+        assert_eq!(parse_timestamp("1:60"), None);
+        assert_eq!(parse_timestamp("1:00:00"), Some(3600));
+        assert_eq!(parse_timestamp("abc"), None);
+        assert_eq!(Chapter { seconds: 3723, title: String::new() }.timestamp(), "1:02:03");
+        assert_eq!(Chapter { seconds: 65, title: String::new() }.timestamp(), "1:05");
+    }
+
+    #[test]
+    fn counts_tag_characters_like_youtube() {
+        let tags = vec!["drone".to_string(), "lake view".to_string()];
+        assert_eq!(tag_characters(&tags), 5 + 9 + 2 + 1);
+        assert_eq!(tag_characters(&[]), 0);
+    }
+
+    #[test]
+    fn warns_about_chapters_youtube_ignores() {
+        let chapter = |seconds, title: &str| Chapter { seconds, title: title.to_string() };
+        let info = UploadInfo {
+            titles: vec!["Title".to_string()],
+            chapters: vec![chapter(5, "Intro"), chapter(10, "Main")],
+            ..Default::default()
+        };
+        let warnings = info.warnings();
+
+        assert!(warnings.contains(&"First chapter must start at 0:00".to_string()));
+        assert!(warnings.contains(&format!("YouTube needs at least {} chapters", MIN_CHAPTERS)));
+        assert!(warnings.iter().any(|w| w.starts_with("Chapter 'Intro' at 0:05 is shorter")));
+        assert!(UploadInfo::parse(IDEAS).unwrap().warnings().is_empty());
+    }
+}